@@ -0,0 +1,251 @@
+use crate::sturm::isolate_real_root;
+use crate::{BracketingMethod, DensePolynom, NewtonOptions, Polynom, RootError, RootReport};
+
+/// Upper bounds on the magnitude of the roots of a polynom, see [`Polynom::root_bound`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct RootBounds {
+    /// `1 + max |a_i / a_n|`.
+    pub cauchy: f64,
+    /// `2 max |a_(n-k) / a_n|^(1/k)`, with `a_0` halved.
+    pub fujiwara: f64,
+    /// `max(1, sum |a_i / a_n|)`.
+    pub lagrange: f64,
+}
+
+impl RootBounds {
+    /// The smallest and therefore sharpest of the bounds.
+    pub fn best(&self) -> f64 {
+        self.cauchy.min(self.fujiwara).min(self.lagrange)
+    }
+}
+
+impl Polynom {
+    /// Bounds on `|z|` for all roots `z` of the polynom.
+    ///
+    /// The bounds are computed for the polynom without the root `0` and without negative
+    /// exponents, whose coefficients are `a_0, ..., a_n`. All bounds are zero if there are no
+    /// nonzero roots.
+    pub fn root_bound(&self) -> RootBounds {
+        let (_, coefficients) = self.root_coefficients();
+        let degree = coefficients.len().saturating_sub(1);
+        if degree == 0 {
+            return RootBounds {
+                cauchy: 0.,
+                fujiwara: 0.,
+                lagrange: 0.,
+            };
+        }
+        let lead = coefficients[degree].abs();
+        let ratios = coefficients[..degree].iter().map(|c| c.abs() / lead);
+        let fujiwara = ratios
+            .clone()
+            .enumerate()
+            .map(|(i, ratio)| {
+                let ratio = if i == 0 { ratio / 2. } else { ratio };
+                ratio.powf(1. / (degree - i) as f64)
+            })
+            .fold(0., f64::max);
+        RootBounds {
+            cauchy: 1. + ratios.clone().fold(0., f64::max),
+            fujiwara: 2. * fujiwara,
+            lagrange: ratios.sum::<f64>().max(1.),
+        }
+    }
+
+    /// The sign changes in the coefficients of `p(x)` and `p(-x)`, which by Descartes' rule of
+    /// signs bound the number of positive and negative roots, counted with multiplicity.
+    ///
+    /// The bounds are exact up to an even number, so an odd count guarantees a root.
+    pub fn descartes_sign_changes(&self) -> (usize, usize) {
+        let (_, coefficients) = self.root_coefficients();
+        let negated = coefficients
+            .iter()
+            .enumerate()
+            .map(|(i, c)| if i % 2 == 0 { *c } else { -c });
+        (
+            sign_changes(coefficients.iter().copied()),
+            sign_changes(negated),
+        )
+    }
+
+    /// Finds a real root without a starting guess or an interval.
+    ///
+    /// Descartes' rule of signs decides on which side of zero to search and
+    /// [`Polynom::root_bound`] limits the search. With an odd number of sign changes the root is
+    /// bracketed between zero and the bound and found by Brent's method. Otherwise the
+    /// [Sturm sequence](Polynom::sturm_sequence) decides whether there are real roots and
+    /// isolates one of them, which is found by Brent's method if the polynom changes sign around
+    /// it and by [`Polynom::find_multiple_root_with`] if it is a root of even multiplicity.
+    pub fn find_real_root(&self) -> Result<RootReport, RootError> {
+        let (zero_roots, coefficients) = self.root_coefficients();
+        if zero_roots > 0 {
+            return Ok(RootReport {
+                root: 0.,
+                residual: 0.,
+                iterations: 0,
+            });
+        }
+        let (positive, negative) = self.descartes_sign_changes();
+        // Slightly beyond the bound, so that no root lies on the end of an interval.
+        let bound = 1. + self.root_bound().best();
+        let polynomial = Polynom::from(DensePolynom::new(coefficients));
+        let report = if positive % 2 == 1 {
+            polynomial.find_root_in(0., bound, BracketingMethod::Brent)?
+        } else if negative % 2 == 1 {
+            polynomial.find_root_in(-bound, 0., BracketingMethod::Brent)?
+        } else {
+            let (lo, hi) = isolate_real_root(&polynomial, bound).ok_or(RootError::NoRealRoots)?;
+            if polynomial.eval(lo) * polynomial.eval(hi) <= 0. {
+                polynomial.find_root_in(lo, hi, BracketingMethod::Brent)?
+            } else {
+                polynomial.find_multiple_root_with((lo + hi) / 2., NewtonOptions::default())?
+            }
+        };
+        Ok(RootReport {
+            residual: self.eval(report.root),
+            ..report
+        })
+    }
+}
+
+fn sign_changes(coefficients: impl Iterator<Item = f64>) -> usize {
+    let mut changes = 0;
+    let mut previous = 0.;
+    for coefficient in coefficients.filter(|c| *c != 0.) {
+        if previous * coefficient < 0. {
+            changes += 1;
+        }
+        previous = coefficient;
+    }
+    changes
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use assert_approx_eq::assert_approx_eq;
+
+    #[test]
+    fn root_bounds_exercise_sheet() {
+        // given:
+        let under_test = Polynom::new()
+            .add_term(1., 3)
+            .add_term(-2., 2)
+            .add_term(-11., 1)
+            .add_term(12., 0);
+
+        // when:
+        let actual = under_test.root_bound();
+
+        // then:
+        assert_eq!(actual.cauchy, 13.);
+        assert_eq!(actual.lagrange, 25.);
+        assert_approx_eq!(actual.fujiwara, 2. * 11f64.sqrt(), 1e-12);
+        assert_eq!(actual.best(), actual.fujiwara);
+        for root in under_test.roots() {
+            assert!(root.norm() <= actual.best());
+        }
+        assert_eq!(Polynom::new().add_term(3., 2).root_bound().best(), 0.);
+    }
+
+    #[test]
+    fn descartes_sign_changes_bound_real_roots() {
+        // given:
+        let three_real = Polynom::new()
+            .add_term(1., 3)
+            .add_term(-2., 2)
+            .add_term(-11., 1)
+            .add_term(12., 0);
+        let laurent = Polynom::new()
+            .add_term(1., 2)
+            .add_term(1., 0)
+            .add_term(-2., -1);
+
+        // then:
+        assert_eq!(three_real.descartes_sign_changes(), (2, 1));
+        assert_eq!(laurent.descartes_sign_changes(), (1, 0));
+        assert_eq!(
+            Polynom::new()
+                .add_term(1., 4)
+                .add_term(1., 0)
+                .descartes_sign_changes(),
+            (0, 0)
+        );
+    }
+
+    #[test]
+    fn find_real_root_without_guess() {
+        // given:
+        let odd = Polynom::new().add_term(1., 3).add_term(-8., 0);
+        let even = Polynom::new()
+            .add_term(2., 4)
+            .add_term(7., 3)
+            .add_term(6., 2)
+            .add_term(8., 1)
+            .add_term(12., 0);
+        let laurent = Polynom::new()
+            .add_term(1., 1)
+            .add_term(4., 0)
+            .add_term(4., -1);
+
+        // then:
+        assert_approx_eq!(odd.find_real_root().unwrap().root, 2., 1e-12);
+        let actual = even.find_real_root().unwrap();
+        assert!(actual.residual.abs() < 1e-9);
+        assert_approx_eq!(actual.root, -2.5943, 0.0001);
+        assert_approx_eq!(laurent.find_real_root().unwrap().root, -2., 1e-6);
+        assert_eq!(
+            Polynom::new()
+                .add_term(1., 2)
+                .add_term(1., 0)
+                .find_real_root(),
+            Err(RootError::NoRealRoots)
+        );
+    }
+
+    #[test]
+    fn find_real_root_with_even_sign_changes() {
+        // given:
+        let no_real_roots = Polynom::new()
+            .add_term(1., 2)
+            .add_term(2., 1)
+            .add_term(5., 0);
+        let fifth_roots_of_unity = Polynom::new()
+            .add_term(1., 4)
+            .add_term(1., 3)
+            .add_term(1., 2)
+            .add_term(1., 1)
+            .add_term(1., 0);
+        // (x - 1)^2 (x - 3) (x + 2), with two sign changes for positive roots
+        let two_positive = Polynom::new()
+            .add_term(1., 4)
+            .add_term(-3., 3)
+            .add_term(-3., 2)
+            .add_term(11., 1)
+            .add_term(-6., 0);
+        // (x - 2)^2 (x^2 + 1)
+        let double_root = Polynom::new()
+            .add_term(1., 4)
+            .add_term(-4., 3)
+            .add_term(5., 2)
+            .add_term(-4., 1)
+            .add_term(4., 0);
+
+        // then:
+        assert_eq!(no_real_roots.find_real_root(), Err(RootError::NoRealRoots));
+        assert_eq!(
+            fifth_roots_of_unity.find_real_root(),
+            Err(RootError::NoRealRoots)
+        );
+        let actual = two_positive.find_real_root().unwrap().root;
+        assert!(
+            [-2., 1., 3.]
+                .iter()
+                .any(|root| (actual - root).abs() < 1e-9),
+            "{}",
+            actual
+        );
+        // A double root is only determined to about the square root of the rounding error.
+        assert_approx_eq!(double_root.find_real_root().unwrap().root, 2., 1e-7);
+    }
+}
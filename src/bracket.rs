@@ -0,0 +1,277 @@
+use crate::{Polynom, RootError, RootReport};
+
+/// Enough steps to bisect any finite interval down to the tolerance.
+const MAX_ITERATIONS: usize = 1100;
+
+/// The methods of [`Polynom::find_root_in`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BracketingMethod {
+    /// Halves the interval in every step, slow but predictable.
+    Bisection,
+    /// Brent's method, which combines inverse quadratic interpolation, secant steps and
+    /// bisection.
+    Brent,
+    /// False position with the Illinois modification, which halves the value of an endpoint that
+    /// is kept twice in a row so that both ends converge.
+    Illinois,
+}
+
+/// The absolute tolerance for a root near `x`.
+fn tolerance(x: f64) -> f64 {
+    4. * f64::EPSILON * x.abs().max(1.)
+}
+
+impl Polynom {
+    /// Finds a root in the interval between `a` and `b`, which is guaranteed to succeed when the
+    /// polynom has opposite signs at `a` and `b`.
+    pub fn find_root_in(
+        &self,
+        a: f64,
+        b: f64,
+        method: BracketingMethod,
+    ) -> Result<RootReport, RootError> {
+        let f = |x| self.eval(x);
+        let (fa, fb) = (f(a), f(b));
+        if fa.is_nan() || fb.is_nan() {
+            return Err(RootError::NotANumber { iterations: 0 });
+        }
+        let (root, iterations) = if fa == 0. {
+            (a, 0)
+        } else if fb == 0. {
+            (b, 0)
+        } else if fa.signum() == fb.signum() {
+            return Err(RootError::NotBracketed { a, b });
+        } else {
+            let result = match method {
+                BracketingMethod::Bisection => bisection(f, a, b, fa),
+                BracketingMethod::Brent => brent(f, a, b, fa, fb),
+                BracketingMethod::Illinois => illinois(f, a, b, fa, fb),
+            };
+            result.map_err(|x| RootError::MaxIterationsReached { x, residual: f(x) })?
+        };
+        Ok(RootReport {
+            root,
+            residual: f(root),
+            iterations,
+        })
+    }
+}
+
+fn bisection(
+    f: impl Fn(f64) -> f64,
+    mut a: f64,
+    mut b: f64,
+    mut fa: f64,
+) -> Result<(f64, usize), f64> {
+    for iteration in 1..=MAX_ITERATIONS {
+        let mid = a + (b - a) / 2.;
+        let value = f(mid);
+        if value == 0. || (b - a).abs() / 2. <= tolerance(mid) {
+            return Ok((mid, iteration));
+        }
+        if value.signum() == fa.signum() {
+            a = mid;
+            fa = value;
+        } else {
+            b = mid;
+        }
+    }
+    Err(a + (b - a) / 2.)
+}
+
+fn brent(
+    f: impl Fn(f64) -> f64,
+    mut a: f64,
+    mut b: f64,
+    mut fa: f64,
+    mut fb: f64,
+) -> Result<(f64, usize), f64> {
+    // c is the point opposite to b, d the last step and e the step before it.
+    let (mut c, mut fc) = (a, fa);
+    let mut d = b - a;
+    let mut e = d;
+    for iteration in 1..=MAX_ITERATIONS {
+        if fb.signum() == fc.signum() {
+            c = a;
+            fc = fa;
+            d = b - a;
+            e = d;
+        }
+        if fc.abs() < fb.abs() {
+            a = b;
+            b = c;
+            c = a;
+            fa = fb;
+            fb = fc;
+            fc = fa;
+        }
+        let tol = tolerance(b) / 2.;
+        let half = (c - b) / 2.;
+        if half.abs() <= tol || fb == 0. {
+            return Ok((b, iteration));
+        }
+        if e.abs() >= tol && fa.abs() > fb.abs() {
+            let s = fb / fa;
+            let (mut p, mut q) = if a == c {
+                // secant step
+                (2. * half * s, 1. - s)
+            } else {
+                // inverse quadratic interpolation
+                let q = fa / fc;
+                let r = fb / fc;
+                (
+                    s * (2. * half * q * (q - r) - (b - a) * (r - 1.)),
+                    (q - 1.) * (r - 1.) * (s - 1.),
+                )
+            };
+            if p > 0. {
+                q = -q;
+            }
+            p = p.abs();
+            // Accept the interpolation only if it stays inside the bracket and shrinks fast enough.
+            if 2. * p < (3. * half * q - (tol * q).abs()).min((e * q).abs()) {
+                e = d;
+                d = p / q;
+            } else {
+                d = half;
+                e = d;
+            }
+        } else {
+            d = half;
+            e = d;
+        }
+        a = b;
+        fa = fb;
+        b += if d.abs() > tol { d } else { tol.copysign(half) };
+        fb = f(b);
+    }
+    Err(b)
+}
+
+fn illinois(
+    f: impl Fn(f64) -> f64,
+    mut a: f64,
+    mut b: f64,
+    mut fa: f64,
+    mut fb: f64,
+) -> Result<(f64, usize), f64> {
+    for iteration in 1..=MAX_ITERATIONS {
+        let c = (a * fb - b * fa) / (fb - fa);
+        let fc = f(c);
+        if fc == 0. || (c - b).abs() <= tolerance(c) {
+            return Ok((c, iteration));
+        }
+        if fc.signum() == fb.signum() {
+            // b is replaced and a is kept again, so damp it.
+            fa /= 2.;
+        } else {
+            a = b;
+            fa = fb;
+        }
+        b = c;
+        fb = fc;
+        if (b - a).abs() <= tolerance(b) {
+            return Ok((b, iteration));
+        }
+    }
+    Err(b)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use assert_approx_eq::assert_approx_eq;
+
+    const METHODS: [BracketingMethod; 3] = [
+        BracketingMethod::Bisection,
+        BracketingMethod::Brent,
+        BracketingMethod::Illinois,
+    ];
+
+    #[test]
+    fn find_root_in_exercise_sheet() {
+        // given:
+        let under_test = Polynom::new()
+            .add_term(1., 3)
+            .add_term(-2., 2)
+            .add_term(-11., 1)
+            .add_term(12., 0);
+
+        for method in METHODS {
+            // when:
+            let actual = under_test.find_root_in(2., 10., method).unwrap();
+
+            // then:
+            assert_approx_eq!(actual.root, 4., 1e-12);
+            assert!(actual.residual.abs() < 1e-9);
+            assert_approx_eq!(
+                under_test.find_root_in(-10., 0., method).unwrap().root,
+                -3.,
+                1e-12
+            );
+        }
+    }
+
+    #[test]
+    fn brent_and_illinois_beat_bisection() {
+        // given:
+        let under_test = Polynom::new()
+            .add_term(2., 4)
+            .add_term(7., 3)
+            .add_term(6., 2)
+            .add_term(8., 1)
+            .add_term(12., 0);
+
+        // when:
+        let bisection = under_test
+            .find_root_in(-3., -2., BracketingMethod::Bisection)
+            .unwrap();
+        let brent = under_test
+            .find_root_in(-3., -2., BracketingMethod::Brent)
+            .unwrap();
+        let illinois = under_test
+            .find_root_in(-3., -2., BracketingMethod::Illinois)
+            .unwrap();
+
+        // then:
+        assert_approx_eq!(bisection.root, -2.5943, 0.0001);
+        assert_approx_eq!(brent.root, bisection.root, 1e-12);
+        assert_approx_eq!(illinois.root, bisection.root, 1e-12);
+        assert!(brent.iterations < bisection.iterations);
+        assert!(illinois.iterations < bisection.iterations);
+    }
+
+    #[test]
+    fn find_root_in_reversed_interval_and_endpoint_roots() {
+        // given:
+        let under_test = Polynom::new().add_term(1., 2).add_term(-2., 0);
+
+        for method in METHODS {
+            // when:
+            let actual = under_test.find_root_in(2., 0., method).unwrap();
+
+            // then:
+            assert_approx_eq!(actual.root, 2f64.sqrt(), 1e-12);
+        }
+        let actual = Polynom::new()
+            .add_term(1., 1)
+            .find_root_in(0., 1., BracketingMethod::Brent)
+            .unwrap();
+        assert_eq!(actual.root, 0.);
+        assert_eq!(actual.iterations, 0);
+    }
+
+    #[test]
+    fn find_root_in_requires_a_bracket() {
+        // given:
+        let under_test = Polynom::new().add_term(1., 2).add_term(1., 0);
+
+        for method in METHODS {
+            // when:
+            let actual = under_test.find_root_in(-1., 1., method);
+
+            // then:
+            assert_eq!(actual, Err(RootError::NotBracketed { a: -1., b: 1. }));
+        }
+    }
+}
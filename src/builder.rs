@@ -0,0 +1,76 @@
+use crate::{Coefficient, Polynom};
+
+/// Collects terms and builds a [`Polynom`] from all of them at once.
+///
+/// Every [`PolynomBuilder::add_term`] takes constant time and [`PolynomBuilder::build`] sorts the
+/// terms, which takes linear time if they were added in descending order of exponent and
+/// `O(n log n)` otherwise. Chaining [`Polynom::add_term`] instead appends every term in descending
+/// order at the end of the list, which takes `O(n^2)` time for `n` terms.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PolynomBuilder<T = f64> {
+    terms: Vec<(T, i32)>,
+}
+
+impl<T: Coefficient> Default for PolynomBuilder<T> {
+    fn default() -> Self {
+        PolynomBuilder::new()
+    }
+}
+
+impl<T: Coefficient> PolynomBuilder<T> {
+    pub fn new() -> Self {
+        PolynomBuilder { terms: Vec::new() }
+    }
+
+    /// Adds a term in any order. Terms with equal exponents are merged by
+    /// [`PolynomBuilder::build`].
+    pub fn add_term(mut self, coefficient: T, exponent: i32) -> Self {
+        self.terms.push((coefficient, exponent));
+        self
+    }
+
+    /// Builds the polynom in normal form, see [`Polynom::normalize`].
+    pub fn build(self) -> Polynom<T> {
+        self.terms.into_iter().collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn build_merges_like_add_term() {
+        // given:
+        let under_test = PolynomBuilder::new()
+            .add_term(12., 0)
+            .add_term(1., 2)
+            .add_term(0., 5)
+            .add_term(-11., 1)
+            .add_term(3., 2)
+            .add_term(-4., 2);
+
+        // when:
+        let actual = under_test.build();
+
+        // then:
+        assert_eq!(actual.to_string(), "-11x + 12");
+        assert!(actual.is_normalized());
+    }
+
+    #[test]
+    fn million_terms_in_descending_order() {
+        // given:
+        let under_test = (0..1_000_000)
+            .rev()
+            .fold(PolynomBuilder::new(), |builder, e| builder.add_term(1., e));
+
+        // when:
+        let actual = under_test.build();
+
+        // then:
+        assert_eq!(actual.degree(), Some(999_999));
+        assert_eq!(actual.eval(1.), 1_000_000.);
+        assert!(actual.is_normalized());
+    }
+}
@@ -0,0 +1,240 @@
+use crate::roots::{eval_with_derivative, sort_roots};
+use crate::{Coefficient, Complex, Polynom};
+
+/// Newton steps applied to every root to remove the rounding error of the formulas.
+const POLISH_STEPS: usize = 2;
+
+impl Polynom {
+    /// Finds all complex roots of a polynom of degree at most four by the classical formulas, or
+    /// `None` for higher degrees.
+    ///
+    /// Quadratics use the citardauq form, which avoids cancellation between `-b` and the square
+    /// root of the discriminant, cubics Cardano's formula or its trigonometric form when all roots
+    /// are real and quartics Ferrari's method. Like [`Polynom::roots`] the roots are repeated
+    /// according to their multiplicity, sorted by real part and include the root `0` split off
+    /// from the lowest exponent.
+    pub fn solve_closed_form(&self) -> Option<Vec<Complex>> {
+        let (zero_roots, coefficients) = self.root_coefficients();
+        let mut roots = match coefficients.len() {
+            0 | 1 => Vec::new(),
+            2 => vec![Complex::from(-coefficients[0] / coefficients[1])],
+            3 => quadratic(coefficients[2], coefficients[1], coefficients[0]).to_vec(),
+            4 => {
+                let lead = coefficients[3];
+                cubic(
+                    coefficients[2] / lead,
+                    coefficients[1] / lead,
+                    coefficients[0] / lead,
+                )
+                .to_vec()
+            }
+            5 => {
+                let lead = coefficients[4];
+                quartic(
+                    coefficients[3] / lead,
+                    coefficients[2] / lead,
+                    coefficients[1] / lead,
+                    coefficients[0] / lead,
+                )
+                .to_vec()
+            }
+            _ => return None,
+        };
+        for root in roots.iter_mut() {
+            *root = polish(&coefficients, *root);
+        }
+        roots.extend(std::iter::repeat_n(Complex::zero(), zero_roots));
+        sort_roots(&mut roots);
+        Some(roots)
+    }
+}
+
+/// The roots of `a x^2 + b x + c` with real coefficients.
+fn quadratic(a: f64, b: f64, c: f64) -> [Complex; 2] {
+    let discriminant = b * b - 4. * a * c;
+    if discriminant >= 0. {
+        let q = -(b + discriminant.sqrt().copysign(b)) / 2.;
+        if q == 0. {
+            return [Complex::zero(); 2];
+        }
+        [Complex::from(q / a), Complex::from(c / q)]
+    } else {
+        let re = -b / (2. * a);
+        let im = (-discriminant).sqrt() / (2. * a);
+        [Complex::new(re, im), Complex::new(re, -im)]
+    }
+}
+
+/// The roots of the monic cubic `x^3 + a x^2 + b x + c`, starting with a real one.
+fn cubic(a: f64, b: f64, c: f64) -> [Complex; 3] {
+    let q = (a * a - 3. * b) / 9.;
+    let r = (2. * a * a * a - 9. * a * b + 27. * c) / 54.;
+    let shift = a / 3.;
+    if r * r < q * q * q {
+        // Three real roots, which Cardano's formula would reach only through complex numbers.
+        let theta = (r / (q * q * q).sqrt()).acos();
+        let scale = -2. * q.sqrt();
+        let third = 2. * std::f64::consts::PI / 3.;
+        [0., third, -third].map(|offset| Complex::from(scale * (theta / 3. + offset).cos() - shift))
+    } else {
+        let big_a = -(r.abs() + (r * r - q * q * q).sqrt()).cbrt().copysign(r);
+        let big_b = if big_a == 0. { 0. } else { q / big_a };
+        let re = -(big_a + big_b) / 2. - shift;
+        let im = 3f64.sqrt() / 2. * (big_a - big_b);
+        [
+            Complex::from(big_a + big_b - shift),
+            Complex::new(re, im),
+            Complex::new(re, -im),
+        ]
+    }
+}
+
+/// The roots of the monic quartic `x^4 + a x^3 + b x^2 + c x + d`.
+fn quartic(a: f64, b: f64, c: f64, d: f64) -> [Complex; 4] {
+    // Substituting x = y - a/4 gives the depressed quartic y^4 + p y^2 + q y + r.
+    let shift = a / 4.;
+    let p = b - 6. * shift * shift;
+    let q = c - 2. * b * shift + 8. * shift * shift * shift;
+    let r = d - c * shift + b * shift * shift - 3. * shift * shift * shift * shift;
+    let roots = if q == 0. {
+        // Biquadratic: a quadratic in y^2.
+        let [first, second] = quadratic(1., p, r);
+        let (first, second) = (first.sqrt(), second.sqrt());
+        [first, -first, second, -second]
+    } else {
+        // Ferrari: for a root m > 0 of the resolvent cubic both sides of
+        // (y^2 + p/2 + m)^2 = 2m (y - q/(4m))^2 are squares.
+        let m = cubic(p, p * p / 4. - r, -q * q / 8.)
+            .iter()
+            .filter(|root| root.im == 0.)
+            .map(|root| root.re)
+            .fold(f64::NEG_INFINITY, f64::max);
+        let s = (2. * m).sqrt();
+        let [first, second] = quadratic(1., -s, p / 2. + m + q / (2. * s));
+        let [third, fourth] = quadratic(1., s, p / 2. + m - q / (2. * s));
+        [first, second, third, fourth]
+    };
+    roots.map(|y| y - Complex::from(shift))
+}
+
+/// Newton steps on the polynom with ascending `coefficients`, each kept only if it lowers the
+/// residual, which protects multiple roots.
+fn polish(coefficients: &[f64], mut root: Complex) -> Complex {
+    let (mut value, mut derivative) = eval_with_derivative(coefficients, root);
+    for _ in 0..POLISH_STEPS {
+        if value == Complex::zero() || derivative == Complex::zero() {
+            break;
+        }
+        let next = root - value / derivative;
+        let (next_value, next_derivative) = eval_with_derivative(coefficients, next);
+        if next_value.norm() >= value.norm() {
+            break;
+        }
+        root = next;
+        value = next_value;
+        derivative = next_derivative;
+    }
+    root
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::roots::assert_roots;
+    use assert_approx_eq::assert_approx_eq;
+
+    #[test]
+    fn solve_linear_and_quadratic() {
+        // given:
+        let linear = Polynom::new().add_term(2., 1).add_term(-3., 0);
+        let quadratic = Polynom::new()
+            .add_term(1., 2)
+            .add_term(2., 1)
+            .add_term(5., 0);
+        let cancelling = Polynom::new()
+            .add_term(1., 2)
+            .add_term(1e8, 1)
+            .add_term(1., 0);
+
+        // when:
+        let actual = cancelling.solve_closed_form().unwrap();
+
+        // then:
+        assert_roots(&linear.solve_closed_form().unwrap(), &[(1.5, 0.)], 1e-9);
+        assert_roots(
+            &quadratic.solve_closed_form().unwrap(),
+            &[(-1., -2.), (-1., 2.)],
+            1e-9,
+        );
+        assert_approx_eq!(actual[0].re, -1e8, 1e-6);
+        assert_approx_eq!(actual[1].re / -1e-8, 1., 1e-15);
+    }
+
+    #[test]
+    fn solve_cubics() {
+        // given:
+        let three_real = Polynom::new()
+            .add_term(1., 3)
+            .add_term(-2., 2)
+            .add_term(-11., 1)
+            .add_term(12., 0);
+        let one_real = Polynom::new().add_term(1., 3).add_term(-8., 0);
+
+        // then:
+        assert_roots(
+            &three_real.solve_closed_form().unwrap(),
+            &[(-3., 0.), (1., 0.), (4., 0.)],
+            1e-9,
+        );
+        assert_roots(
+            &one_real.solve_closed_form().unwrap(),
+            &[(-1., -3f64.sqrt()), (-1., 3f64.sqrt()), (2., 0.)],
+            1e-9,
+        );
+    }
+
+    #[test]
+    fn solve_quartics() {
+        // given:
+        let ferrari = Polynom::new()
+            .add_term(2., 4)
+            .add_term(7., 3)
+            .add_term(6., 2)
+            .add_term(8., 1)
+            .add_term(12., 0);
+        let biquadratic = Polynom::new().add_term(1., 4).add_term(-1., 0);
+
+        // when:
+        let actual = ferrari.solve_closed_form().unwrap();
+
+        // then:
+        let expected: Vec<_> = ferrari
+            .roots_with(Default::default())
+            .roots
+            .iter()
+            .map(|z| (z.re, z.im))
+            .collect();
+        assert_roots(&actual, &expected, 1e-9);
+        assert_roots(
+            &biquadratic.solve_closed_form().unwrap(),
+            &[(-1., 0.), (0., -1.), (0., 1.), (1., 0.)],
+            1e-9,
+        );
+    }
+
+    #[test]
+    fn solve_closed_form_splits_off_zero_roots() {
+        // given:
+        let under_test = Polynom::new().add_term(1., 6).add_term(-4., 4);
+        let quintic = Polynom::new().add_term(1., 5).add_term(1., 0);
+
+        // then:
+        assert_roots(
+            &under_test.solve_closed_form().unwrap(),
+            &[(-2., 0.), (0., 0.), (0., 0.), (0., 0.), (0., 0.), (2., 0.)],
+            1e-9,
+        );
+        assert_eq!(quintic.solve_closed_form(), None);
+        assert_eq!(Polynom::new().solve_closed_form(), Some(Vec::new()));
+    }
+}
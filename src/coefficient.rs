@@ -0,0 +1,177 @@
+use std::fmt::{Debug, Display};
+use std::ops::{Add, Div, Mul, Neg, Sub};
+
+/// The arithmetic a [`Polynom`](crate::Polynom) needs from its coefficients.
+pub trait Coefficient:
+    Copy
+    + PartialEq
+    + Debug
+    + Display
+    + Add<Output = Self>
+    + Sub<Output = Self>
+    + Mul<Output = Self>
+    + Neg<Output = Self>
+{
+    fn zero() -> Self;
+
+    fn one() -> Self;
+
+    /// Converts an exponent into a coefficient, as needed for differentiation.
+    fn from_i32(n: i32) -> Self;
+
+    /// Whether `Display` prints this value with a leading minus sign. Terms after the first are
+    /// joined with `" + "` unless this returns `true`.
+    fn is_negative(&self) -> bool;
+
+    /// Whether `Display` wraps this value in parentheses, as needed for complex numbers.
+    fn needs_parentheses(&self) -> bool {
+        false
+    }
+
+    /// Raises `self` to the power `exponent`.
+    ///
+    /// # Panics
+    ///
+    /// The default implementation panics for negative exponents, types with division override it.
+    fn powi(self, exponent: i32) -> Self {
+        assert!(
+            exponent >= 0,
+            "negative exponent {} requires a coefficient type with division",
+            exponent
+        );
+        pow_by_squaring(self, exponent as u32)
+    }
+}
+
+/// Coefficients that also support division, which is required for polynomial division.
+pub trait Field: Coefficient + Div<Output = Self> {}
+
+pub(crate) fn pow_by_squaring<T: Coefficient>(mut base: T, mut exponent: u32) -> T {
+    let mut result = T::one();
+    while exponent > 0 {
+        if exponent & 1 == 1 {
+            result = result * base;
+        }
+        exponent >>= 1;
+        if exponent > 0 {
+            base = base * base;
+        }
+    }
+    result
+}
+
+macro_rules! impl_float_coefficient {
+    ($T:ident) => {
+        impl Coefficient for $T {
+            fn zero() -> Self {
+                0.
+            }
+
+            fn one() -> Self {
+                1.
+            }
+
+            fn from_i32(n: i32) -> Self {
+                n as $T
+            }
+
+            fn is_negative(&self) -> bool {
+                *self < 0.
+            }
+
+            fn powi(self, exponent: i32) -> Self {
+                $T::powi(self, exponent)
+            }
+        }
+
+        impl Field for $T {}
+    };
+}
+
+impl_float_coefficient!(f64);
+impl_float_coefficient!(f32);
+
+macro_rules! impl_integer_coefficient {
+    ($T:ident) => {
+        impl Coefficient for $T {
+            fn zero() -> Self {
+                0
+            }
+
+            fn one() -> Self {
+                1
+            }
+
+            fn from_i32(n: i32) -> Self {
+                n as $T
+            }
+
+            fn is_negative(&self) -> bool {
+                *self < 0
+            }
+        }
+    };
+}
+
+impl_integer_coefficient!(i64);
+impl_integer_coefficient!(i128);
+
+#[cfg(test)]
+mod tests {
+    use crate::Polynom;
+
+    #[test]
+    fn integer_polynoms() {
+        // given:
+        let under_test = Polynom::<i64>::default()
+            .add_term(-1, 3)
+            .add_term(2, 2)
+            .add_term(-11, 1)
+            .add_term(12, 0);
+
+        // when:
+        let actual = under_test.differentiate();
+
+        // then:
+        assert_eq!(actual.to_string(), "-3x^2 + 4x -11");
+        assert_eq!(under_test.eval(2), -10);
+        assert_eq!(under_test.eval(-3), 90);
+    }
+
+    #[test]
+    fn exact_big_integer_arithmetic() {
+        // given:
+        let p = Polynom::<i128>::default()
+            .add_term(1, 1)
+            .add_term(1_000_000_007, 0);
+
+        // when:
+        let actual = &p * &p;
+
+        // then:
+        assert_eq!(
+            actual.to_string(),
+            "1x^2 + 2000000014x + 1000000014000000049"
+        );
+        assert_eq!(actual.eval(1_000_000_000), 4_000_000_028_000_000_049);
+    }
+
+    #[test]
+    fn f32_polynoms() {
+        // given:
+        let under_test = Polynom::<f32>::default().add_term(0.5, 2).add_term(1., -1);
+
+        // when:
+        let actual = under_test.eval(2.);
+
+        // then:
+        assert_eq!(actual, 2.5);
+        assert_eq!(under_test.differentiate().to_string(), "1x -1x^-2");
+    }
+
+    #[test]
+    #[should_panic(expected = "negative exponent")]
+    fn integer_polynoms_with_negative_exponents() {
+        Polynom::<i64>::default().add_term(1, -1).eval(2);
+    }
+}
@@ -0,0 +1,323 @@
+use crate::roots::sort_roots;
+use crate::{Coefficient, Complex, Polynom, RootsReport};
+
+/// QR iterations allowed per eigenvalue before giving up.
+const MAX_ITERATIONS: usize = 30;
+
+const RADIX: f64 = 2.;
+
+impl Polynom {
+    /// Finds all complex roots as the eigenvalues of the companion matrix.
+    ///
+    /// The matrix is balanced and then reduced to quasi-triangular form by the QR algorithm with
+    /// Francis double shifts, which is backward stable and therefore a useful cross-check for the
+    /// iterative root finders. `iterations` counts the QR steps; if some eigenvalue does not
+    /// converge, the remaining diagonal entries are reported as roots and `converged` is false.
+    pub fn companion_roots(&self) -> RootsReport {
+        let (zero_roots, coefficients) = self.root_coefficients();
+        let mut matrix = companion_matrix(&coefficients);
+        balance(&mut matrix);
+        let (mut roots, iterations, converged) = hqr(matrix);
+        roots.extend(std::iter::repeat_n(Complex::zero(), zero_roots));
+        sort_roots(&mut roots);
+        RootsReport {
+            roots,
+            iterations,
+            converged,
+        }
+    }
+}
+
+/// The upper Hessenberg companion matrix of the polynom with ascending `coefficients`, whose
+/// first row holds the negated coefficients of the monic polynom.
+fn companion_matrix(coefficients: &[f64]) -> Vec<Vec<f64>> {
+    let degree = coefficients.len().saturating_sub(1);
+    let mut matrix = vec![vec![0.; degree]; degree];
+    for j in 0..degree {
+        matrix[0][j] = -coefficients[degree - 1 - j] / coefficients[degree];
+        if j > 0 {
+            matrix[j][j - 1] = 1.;
+        }
+    }
+    matrix
+}
+
+/// Scales rows and columns by powers of the radix until they have similar norms, which leaves
+/// the eigenvalues unchanged but reduces their rounding errors.
+fn balance(matrix: &mut [Vec<f64>]) {
+    let n = matrix.len();
+    let mut done = false;
+    while !done {
+        done = true;
+        for i in 0..n {
+            let mut column: f64 = matrix
+                .iter()
+                .enumerate()
+                .filter(|(j, _)| *j != i)
+                .map(|(_, row)| row[i].abs())
+                .sum();
+            let row: f64 = matrix[i]
+                .iter()
+                .enumerate()
+                .filter(|(j, _)| *j != i)
+                .map(|(_, v)| v.abs())
+                .sum();
+            if column == 0. || row == 0. {
+                continue;
+            }
+            let sum = column + row;
+            let mut factor = 1.;
+            while column < row / RADIX {
+                factor *= RADIX;
+                column *= RADIX * RADIX;
+            }
+            while column > row * RADIX {
+                factor /= RADIX;
+                column /= RADIX * RADIX;
+            }
+            if (column + row) / factor < 0.95 * sum {
+                done = false;
+                for v in matrix[i].iter_mut() {
+                    *v /= factor;
+                }
+                for row in matrix.iter_mut() {
+                    row[i] *= factor;
+                }
+            }
+        }
+    }
+}
+
+/// The eigenvalues of an upper Hessenberg matrix by the shifted QR algorithm, returned as
+/// `(eigenvalues, iterations, converged)`.
+fn hqr(mut a: Vec<Vec<f64>>) -> (Vec<Complex>, usize, bool) {
+    let n = a.len();
+    let mut norm = 0.;
+    for (i, row) in a.iter().enumerate() {
+        norm += row[i.saturating_sub(1)..]
+            .iter()
+            .map(|v| v.abs())
+            .sum::<f64>();
+    }
+    let mut eigenvalues = Vec::with_capacity(n);
+    let mut iterations = 0;
+    // `end` is the size of the leading block that still has to be reduced, `shift` the sum of
+    // the exceptional shifts applied to it so far.
+    let mut end = n;
+    let mut shift = 0.;
+    let mut its = 0;
+    while end > 0 {
+        let nn = end - 1;
+        // Find the start l of the lowest unreduced block.
+        let mut l = 0;
+        for candidate in (1..=nn).rev() {
+            let mut s = a[candidate - 1][candidate - 1].abs() + a[candidate][candidate].abs();
+            if s == 0. {
+                s = norm;
+            }
+            if a[candidate][candidate - 1].abs() + s == s {
+                a[candidate][candidate - 1] = 0.;
+                l = candidate;
+                break;
+            }
+        }
+        let mut x = a[nn][nn];
+        if l == nn {
+            // A 1x1 block.
+            eigenvalues.push(Complex::from(x + shift));
+            end -= 1;
+            its = 0;
+            continue;
+        }
+        let mut y = a[nn - 1][nn - 1];
+        let mut w = a[nn][nn - 1] * a[nn - 1][nn];
+        if l == nn - 1 {
+            // A 2x2 block with a real pair or a complex conjugate pair of eigenvalues.
+            let p = 0.5 * (y - x);
+            let q = p * p + w;
+            let z = q.abs().sqrt();
+            x += shift;
+            if q >= 0. {
+                let z = p + z.copysign(p);
+                let second = if z == 0. { x + z } else { x - w / z };
+                eigenvalues.push(Complex::from(x + z));
+                eigenvalues.push(Complex::from(second));
+            } else {
+                eigenvalues.push(Complex::new(x + p, z));
+                eigenvalues.push(Complex::new(x + p, -z));
+            }
+            end -= 2;
+            its = 0;
+            continue;
+        }
+        if its == MAX_ITERATIONS {
+            let diagonal = a.iter().enumerate().take(end);
+            eigenvalues.extend(diagonal.map(|(i, row)| Complex::from(row[i] + shift)));
+            return (eigenvalues, iterations, false);
+        }
+        if its == 10 || its == 20 {
+            // Exceptional shift to break cycles.
+            shift += x;
+            for (i, row) in a.iter_mut().enumerate().take(end) {
+                row[i] -= x;
+            }
+            let s = a[nn][nn - 1].abs() + a[nn - 1][nn - 2].abs();
+            x = 0.75 * s;
+            y = x;
+            w = -0.4375 * s * s;
+        }
+        its += 1;
+        iterations += 1;
+
+        // Look for two consecutive small subdiagonal elements to start the double shift at m.
+        let mut m = nn - 2;
+        let (mut p, mut q, mut r);
+        loop {
+            let z = a[m][m];
+            r = x - z;
+            let s = y - z;
+            p = (r * s - w) / a[m + 1][m] + a[m][m + 1];
+            q = a[m + 1][m + 1] - z - r - s;
+            r = a[m + 2][m + 1];
+            let s = p.abs() + q.abs() + r.abs();
+            p /= s;
+            q /= s;
+            r /= s;
+            if m == l {
+                break;
+            }
+            let u = a[m][m - 1].abs() * (q.abs() + r.abs());
+            let v = p.abs() * (a[m - 1][m - 1].abs() + z.abs() + a[m + 1][m + 1].abs());
+            if u + v == v {
+                break;
+            }
+            m -= 1;
+        }
+        for i in m + 2..=nn {
+            a[i][i - 2] = 0.;
+            if i != m + 2 {
+                a[i][i - 3] = 0.;
+            }
+        }
+
+        // The double shift QR step on rows l..=nn and columns m..=nn.
+        for k in m..nn {
+            if k != m {
+                p = a[k][k - 1];
+                q = a[k + 1][k - 1];
+                r = if k != nn - 1 { a[k + 2][k - 1] } else { 0. };
+                x = p.abs() + q.abs() + r.abs();
+                if x != 0. {
+                    p /= x;
+                    q /= x;
+                    r /= x;
+                }
+            }
+            let s = (p * p + q * q + r * r).sqrt().copysign(p);
+            if s == 0. {
+                continue;
+            }
+            if k == m {
+                if l != m {
+                    a[k][k - 1] = -a[k][k - 1];
+                }
+            } else {
+                a[k][k - 1] = -s * x;
+            }
+            p += s;
+            x = p / s;
+            y = q / s;
+            let z = r / s;
+            q /= p;
+            r /= p;
+            #[allow(clippy::needless_range_loop)]
+            for j in k..=nn {
+                let mut p = a[k][j] + q * a[k + 1][j];
+                if k != nn - 1 {
+                    p += r * a[k + 2][j];
+                    a[k + 2][j] -= p * z;
+                }
+                a[k + 1][j] -= p * y;
+                a[k][j] -= p * x;
+            }
+            for row in a.iter_mut().take(nn.min(k + 3) + 1).skip(l) {
+                let mut p = x * row[k] + y * row[k + 1];
+                if k != nn - 1 {
+                    p += z * row[k + 2];
+                    row[k + 2] -= p * r;
+                }
+                row[k + 1] -= p * q;
+                row[k] -= p;
+            }
+        }
+    }
+    (eigenvalues, iterations, true)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::roots::assert_roots;
+
+    #[test]
+    fn companion_roots_exercise_sheet() {
+        // given:
+        let under_test = Polynom::new()
+            .add_term(1., 3)
+            .add_term(-2., 2)
+            .add_term(-11., 1)
+            .add_term(12., 0);
+
+        // when:
+        let actual = under_test.companion_roots();
+
+        // then:
+        assert!(actual.converged);
+        assert_roots(&actual.roots, &[(-3., 0.), (1., 0.), (4., 0.)], 1e-12);
+    }
+
+    #[test]
+    fn companion_roots_agree_with_aberth() {
+        // given:
+        let under_test = Polynom::new()
+            .add_term(3., 8)
+            .add_term(-1., 7)
+            .add_term(4., 5)
+            .add_term(2., 4)
+            .add_term(-7., 2)
+            .add_term(1., 1)
+            .add_term(5., 0);
+
+        // when:
+        let actual = under_test.companion_roots();
+
+        // then:
+        assert!(actual.converged);
+        let expected: Vec<_> = under_test.roots().iter().map(|z| (z.re, z.im)).collect();
+        assert_roots(&actual.roots, &expected, 1e-10);
+    }
+
+    #[test]
+    fn companion_roots_of_wilkinson_like_polynom() {
+        // given: (x - 1)(x - 2)...(x - 10) x^2
+        let under_test = (1..=10).fold(Polynom::new().add_term(1., 2), |p, k| {
+            p * Polynom::new().add_term(1., 1).add_term(-(k as f64), 0)
+        });
+
+        // when:
+        let actual = under_test.companion_roots();
+
+        // then:
+        assert!(actual.converged);
+        let expected: Vec<_> = std::iter::repeat_n(0., 2)
+            .chain((1..=10).map(f64::from))
+            .map(|root| (root, 0.))
+            .collect();
+        assert_roots(&actual.roots, &expected, 1e-8);
+        assert!(Polynom::new()
+            .add_term(4., 0)
+            .companion_roots()
+            .roots
+            .is_empty());
+    }
+}
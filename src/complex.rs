@@ -0,0 +1,288 @@
+use crate::coefficient::pow_by_squaring;
+use crate::newton::{newton, NewtonScalar};
+use crate::{Coefficient, Field, NewtonOptions, Polynom, RootError, RootReport};
+use std::ops::{Add, Div, Mul, Neg, Sub};
+
+/// A complex number `re + im * i` with `f64` parts.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct Complex {
+    pub re: f64,
+    pub im: f64,
+}
+
+impl Complex {
+    pub const I: Complex = Complex { re: 0., im: 1. };
+
+    pub fn new(re: f64, im: f64) -> Complex {
+        Complex { re, im }
+    }
+
+    /// Creates the complex number with absolute value `r` and argument `theta`.
+    pub fn from_polar(r: f64, theta: f64) -> Complex {
+        Complex::new(r * theta.cos(), r * theta.sin())
+    }
+
+    /// The absolute value `|z|`.
+    pub fn norm(self) -> f64 {
+        self.re.hypot(self.im)
+    }
+
+    /// The squared absolute value `|z|^2`, which avoids a square root.
+    pub fn norm_sqr(self) -> f64 {
+        self.re * self.re + self.im * self.im
+    }
+
+    /// The argument in `(-pi, pi]`.
+    pub fn arg(self) -> f64 {
+        self.im.atan2(self.re)
+    }
+
+    pub fn conj(self) -> Complex {
+        Complex::new(self.re, -self.im)
+    }
+
+    pub fn recip(self) -> Complex {
+        // Scaling by the larger part avoids overflow and underflow in |z|^2 (Smith's algorithm).
+        if self.re.abs() >= self.im.abs() {
+            let ratio = self.im / self.re;
+            let denominator = self.re + self.im * ratio;
+            Complex::new(1. / denominator, -ratio / denominator)
+        } else {
+            let ratio = self.re / self.im;
+            let denominator = self.re * ratio + self.im;
+            Complex::new(ratio / denominator, -1. / denominator)
+        }
+    }
+
+    /// The principal square root, with a non-negative real part.
+    pub fn sqrt(self) -> Complex {
+        let r = self.norm();
+        let re = ((r + self.re) / 2.).sqrt();
+        let im = ((r - self.re) / 2.).sqrt();
+        Complex::new(re, im.copysign(self.im))
+    }
+
+    pub fn is_nan(self) -> bool {
+        self.re.is_nan() || self.im.is_nan()
+    }
+}
+
+impl From<f64> for Complex {
+    fn from(re: f64) -> Self {
+        Complex::new(re, 0.)
+    }
+}
+
+impl std::fmt::Display for Complex {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let sign = if self.im.is_sign_negative() { '-' } else { '+' };
+        write!(f, "{}{}{}i", self.re, sign, self.im.abs())
+    }
+}
+
+impl Add for Complex {
+    type Output = Complex;
+
+    fn add(self, rhs: Complex) -> Complex {
+        Complex::new(self.re + rhs.re, self.im + rhs.im)
+    }
+}
+
+impl Sub for Complex {
+    type Output = Complex;
+
+    fn sub(self, rhs: Complex) -> Complex {
+        Complex::new(self.re - rhs.re, self.im - rhs.im)
+    }
+}
+
+impl Mul for Complex {
+    type Output = Complex;
+
+    fn mul(self, rhs: Complex) -> Complex {
+        Complex::new(
+            self.re * rhs.re - self.im * rhs.im,
+            self.re * rhs.im + self.im * rhs.re,
+        )
+    }
+}
+
+impl Div for Complex {
+    type Output = Complex;
+
+    #[allow(clippy::suspicious_arithmetic_impl)]
+    fn div(self, rhs: Complex) -> Complex {
+        self * rhs.recip()
+    }
+}
+
+impl Mul<f64> for Complex {
+    type Output = Complex;
+
+    fn mul(self, rhs: f64) -> Complex {
+        Complex::new(self.re * rhs, self.im * rhs)
+    }
+}
+
+impl Div<f64> for Complex {
+    type Output = Complex;
+
+    fn div(self, rhs: f64) -> Complex {
+        Complex::new(self.re / rhs, self.im / rhs)
+    }
+}
+
+impl Neg for Complex {
+    type Output = Complex;
+
+    fn neg(self) -> Complex {
+        Complex::new(-self.re, -self.im)
+    }
+}
+
+impl Coefficient for Complex {
+    fn zero() -> Self {
+        Complex::new(0., 0.)
+    }
+
+    fn one() -> Self {
+        Complex::new(1., 0.)
+    }
+
+    fn from_i32(n: i32) -> Self {
+        Complex::from(n as f64)
+    }
+
+    fn is_negative(&self) -> bool {
+        false
+    }
+
+    fn needs_parentheses(&self) -> bool {
+        true
+    }
+
+    fn powi(self, exponent: i32) -> Self {
+        let power = pow_by_squaring(self, exponent.unsigned_abs());
+        if exponent < 0 {
+            power.recip()
+        } else {
+            power
+        }
+    }
+}
+
+impl Field for Complex {}
+
+impl NewtonScalar for Complex {
+    fn magnitude(self) -> f64 {
+        self.norm()
+    }
+
+    fn is_nan(self) -> bool {
+        Complex::is_nan(self)
+    }
+}
+
+impl Polynom {
+    /// Evaluates the polynom at a complex argument.
+    pub fn eval_complex(&self, z: Complex) -> Complex {
+        self.terms()
+            .fold(Complex::zero(), |sum, (coefficient, exponent)| {
+                sum + z.powi(exponent) * coefficient
+            })
+    }
+
+    /// Newton's method in the complex plane, which can reach complex roots of real polynoms.
+    pub fn find_complex_root_with(
+        &self,
+        guess: Complex,
+        options: NewtonOptions,
+    ) -> Result<RootReport<Complex>, RootError<Complex>> {
+        self.map_coefficients(Complex::from)
+            .find_root_with(guess, options)
+    }
+}
+
+impl Polynom<Complex> {
+    /// Newton's method starting at `guess`, see [`Polynom::find_root_with`].
+    pub fn find_root_with(
+        &self,
+        guess: Complex,
+        options: NewtonOptions,
+    ) -> Result<RootReport<Complex>, RootError<Complex>> {
+        let derivative = self.differentiate();
+        newton(|z| self.eval(z), |z| derivative.eval(z), guess, options)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use assert_approx_eq::assert_approx_eq;
+    use std::f64::consts::PI;
+
+    #[test]
+    fn complex_arithmetic() {
+        // given:
+        let a = Complex::new(1., 2.);
+        let b = Complex::new(3., -1.);
+
+        // then:
+        assert_eq!(a + b, Complex::new(4., 1.));
+        assert_eq!(a - b, Complex::new(-2., 3.));
+        assert_eq!(a * b, Complex::new(5., 5.));
+        assert_eq!((a * b) / b, a);
+        assert_eq!(Complex::new(-4., 0.).sqrt(), Complex::new(0., 2.));
+        assert_eq!(a.to_string(), "1+2i");
+        assert_eq!(b.to_string(), "3-1i");
+    }
+
+    #[test]
+    fn eval_on_unit_circle() {
+        // given:
+        let under_test = Polynom::new().add_term(1., 4).add_term(-1., 0);
+
+        for k in 0..4 {
+            // when:
+            let actual = under_test.eval_complex(Complex::from_polar(1., k as f64 * PI / 2.));
+
+            // then:
+            assert_approx_eq!(actual.norm(), 0., 1e-12);
+        }
+        let actual = under_test.eval_complex(Complex::from_polar(1., PI / 4.));
+        assert_approx_eq!(actual.re, -2., 1e-12);
+        assert_approx_eq!(actual.im, 0., 1e-12);
+    }
+
+    #[test]
+    fn complex_newton_finds_complex_roots() {
+        // given:
+        let under_test = Polynom::new().add_term(1., 2).add_term(1., 0);
+
+        // when:
+        let actual = under_test
+            .find_complex_root_with(Complex::new(0.5, 0.5), NewtonOptions::default())
+            .unwrap();
+
+        // then:
+        assert_approx_eq!(actual.root.re, 0., 1e-12);
+        assert_approx_eq!(actual.root.im, 1., 1e-12);
+        assert!(actual.residual.norm() < 1e-12);
+    }
+
+    #[test]
+    fn complex_coefficients() {
+        // given:
+        let under_test = Polynom::<Complex>::default()
+            .add_term(Complex::I, 1)
+            .add_term(Complex::new(2., -1.), 0);
+
+        // when:
+        let actual = under_test.eval(Complex::I);
+
+        // then:
+        assert_eq!(actual, Complex::new(1., -1.));
+        assert_eq!(under_test.to_string(), "(0+1i)x + (2-1i)");
+        assert_eq!(under_test.differentiate().to_string(), "(0+1i)");
+    }
+}
@@ -0,0 +1,231 @@
+use crate::newton::newton;
+use crate::{fmt_terms, NewtonOptions, Polynom, RootError, RootReport};
+
+/// A polynom stored as a vector of coefficients indexed by exponent.
+///
+/// `coefficients[i]` belongs to the exponent `lowest_exponent + i`. The lowest exponent is `0`
+/// unless the polynom has terms with negative exponents. Trailing zeros are never stored, so the
+/// conversions from and to [`Polynom`] are lossless.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct DensePolynom {
+    coefficients: Vec<f64>,
+    lowest_exponent: i32,
+}
+
+impl DensePolynom {
+    /// Creates a polynom from coefficients in ascending order, `coefficients[i]` belongs to `x^i`.
+    pub fn new(coefficients: Vec<f64>) -> DensePolynom {
+        DensePolynom::with_lowest_exponent(coefficients, 0)
+    }
+
+    fn with_lowest_exponent(mut coefficients: Vec<f64>, lowest_exponent: i32) -> DensePolynom {
+        while coefficients.last() == Some(&0.) {
+            coefficients.pop();
+        }
+        let leading_zeros = coefficients
+            .iter()
+            .take_while(|c| **c == 0.)
+            .count()
+            .min(lowest_exponent.min(0).unsigned_abs() as usize);
+        coefficients.drain(..leading_zeros);
+        DensePolynom {
+            coefficients,
+            lowest_exponent: lowest_exponent + leading_zeros as i32,
+        }
+    }
+
+    /// The coefficients in ascending order starting at [`DensePolynom::lowest_exponent`].
+    pub fn coefficients(&self) -> &[f64] {
+        &self.coefficients
+    }
+
+    pub fn lowest_exponent(&self) -> i32 {
+        self.lowest_exponent
+    }
+
+    /// Returns the highest exponent, or `None` for a polynom without terms.
+    pub fn degree(&self) -> Option<i32> {
+        match self.coefficients.len() {
+            0 => None,
+            len => Some(self.lowest_exponent + len as i32 - 1),
+        }
+    }
+
+    /// Returns an iterator over the nonzero `(coefficient, exponent)` pairs in descending order of
+    /// exponents.
+    pub fn terms(&self) -> impl Iterator<Item = (f64, i32)> + '_ {
+        let lowest_exponent = self.lowest_exponent;
+        self.coefficients
+            .iter()
+            .enumerate()
+            .rev()
+            .filter(|(_, c)| **c != 0.)
+            .map(move |(i, c)| (*c, lowest_exponent + i as i32))
+    }
+
+    /// Evaluates the polynom using Horner's scheme.
+    pub fn eval(&self, x: f64) -> f64 {
+        let value = self
+            .coefficients
+            .iter()
+            .rev()
+            .fold(0., |acc, coefficient| acc * x + coefficient);
+        if self.lowest_exponent == 0 {
+            value
+        } else {
+            value * x.powi(self.lowest_exponent)
+        }
+    }
+
+    pub fn differentiate(&self) -> DensePolynom {
+        if self.lowest_exponent == 0 {
+            let coefficients = self
+                .coefficients
+                .iter()
+                .enumerate()
+                .skip(1)
+                .map(|(i, c)| c * i as f64)
+                .collect();
+            DensePolynom::new(coefficients)
+        } else {
+            let coefficients = self
+                .coefficients
+                .iter()
+                .enumerate()
+                .map(|(i, c)| c * (self.lowest_exponent + i as i32) as f64)
+                .collect();
+            DensePolynom::with_lowest_exponent(coefficients, self.lowest_exponent - 1)
+        }
+    }
+
+    /// Newton's method starting at `guess`, see [`Polynom::find_root_with`].
+    pub fn find_root_with(
+        &self,
+        guess: f64,
+        options: NewtonOptions,
+    ) -> Result<RootReport, RootError> {
+        let derivative = self.differentiate();
+        newton(|x| self.eval(x), |x| derivative.eval(x), guess, options)
+    }
+
+    /// Newton's method starting at `guess`, see [`Polynom::find_root`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if the iteration fails to converge.
+    pub fn find_root(&self, guess: f64) -> f64 {
+        match self.find_root_with(guess, NewtonOptions::default()) {
+            Ok(report) => report.root,
+            Err(error) => panic!("find_root({}) failed: {}", guess, error),
+        }
+    }
+}
+
+impl std::fmt::Display for DensePolynom {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        fmt_terms(f, self.terms())
+    }
+}
+
+impl From<&Polynom> for DensePolynom {
+    fn from(polynom: &Polynom) -> Self {
+        let lowest_exponent = polynom.terms().map(|(_, e)| e).min().unwrap_or(0).min(0);
+        let mut coefficients = match polynom.degree() {
+            Some(degree) => vec![0.; (degree - lowest_exponent) as usize + 1],
+            None => Vec::new(),
+        };
+        for (coefficient, exponent) in polynom.terms() {
+            coefficients[(exponent - lowest_exponent) as usize] += coefficient;
+        }
+        DensePolynom::with_lowest_exponent(coefficients, lowest_exponent)
+    }
+}
+
+impl From<Polynom> for DensePolynom {
+    fn from(polynom: Polynom) -> Self {
+        DensePolynom::from(&polynom)
+    }
+}
+
+impl From<&DensePolynom> for Polynom {
+    fn from(polynom: &DensePolynom) -> Self {
+        polynom.terms().collect()
+    }
+}
+
+impl From<DensePolynom> for Polynom {
+    fn from(polynom: DensePolynom) -> Self {
+        Polynom::from(&polynom)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use assert_approx_eq::assert_approx_eq;
+
+    #[test]
+    fn convert_polynom_to_dense_and_back() {
+        // given:
+        let polynom = Polynom::new()
+            .add_term(1., 3)
+            .add_term(-11., 1)
+            .add_term(12., 0)
+            .add_term(0.5, -2);
+
+        // when:
+        let dense = DensePolynom::from(&polynom);
+        let actual = Polynom::from(&dense);
+
+        // then:
+        assert_eq!(dense.lowest_exponent(), -2);
+        assert_eq!(dense.coefficients(), &[0.5, 0., 12., -11., 0., 1.]);
+        assert_eq!(actual, polynom);
+        assert_eq!(DensePolynom::from(actual), dense);
+    }
+
+    #[test]
+    fn eval_dense_polynoms() {
+        // given:
+        let under_test = DensePolynom::new(vec![12., -4., 2., -5.]);
+
+        // when:
+        let actual = under_test.eval(2.);
+
+        // then:
+        assert_eq!(actual, -28.);
+        assert_eq!(under_test.to_string(), "-5x^3 + 2x^2 -4x + 12");
+    }
+
+    #[test]
+    fn differentiate_dense_polynoms() {
+        // given:
+        let under_test = DensePolynom::from(
+            Polynom::new()
+                .add_term(-1., 3)
+                .add_term(2., 2)
+                .add_term(-11., 1)
+                .add_term(12., 0)
+                .add_term(3., -1),
+        );
+
+        // when:
+        let actual = under_test.differentiate();
+
+        // then:
+        assert_eq!(actual.to_string(), "-3x^2 + 4x -11 -3x^-2");
+        assert_eq!(actual.degree(), Some(2));
+    }
+
+    #[test]
+    fn find_root_dense_polynoms() {
+        // given:
+        let under_test = DensePolynom::new(vec![12., -11., -2., 1.]);
+
+        // when:
+        let actual = under_test.find_root(2.35287527);
+
+        // then:
+        assert_approx_eq!(actual, 4., 0.0001);
+    }
+}
@@ -0,0 +1,186 @@
+use crate::ops::forward_binop;
+use crate::{Field, Polynom};
+use std::collections::BTreeMap;
+use std::ops::{Div, DivAssign, Rem, RemAssign};
+
+/// Error returned when dividing by a polynom without terms.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DivisionByZero;
+
+impl std::fmt::Display for DivisionByZero {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "division by an empty polynom")
+    }
+}
+
+impl std::error::Error for DivisionByZero {}
+
+impl<T: Field> Polynom<T> {
+    /// Polynomial long division, returning `(quotient, remainder)` such that
+    /// `self == quotient * divisor + remainder` and the remainder has a smaller degree than the
+    /// divisor.
+    pub fn div_rem(
+        &self,
+        divisor: &Polynom<T>,
+    ) -> Result<(Polynom<T>, Polynom<T>), DivisionByZero> {
+        let divisor: Vec<(T, i32)> = divisor.terms().collect::<Polynom<T>>().terms().collect();
+        let (lead_coefficient, lead_exponent) = *divisor.first().ok_or(DivisionByZero)?;
+
+        let mut remainder: BTreeMap<i32, T> = BTreeMap::new();
+        for (coefficient, exponent) in self.terms() {
+            let entry = remainder.entry(exponent).or_insert_with(T::zero);
+            *entry = *entry + coefficient;
+        }
+        remainder.retain(|_, c| *c != T::zero());
+
+        let mut quotient = Vec::new();
+        while let Some((&exponent, &coefficient)) = remainder.iter().next_back() {
+            if exponent < lead_exponent {
+                break;
+            }
+            let q = (coefficient / lead_coefficient, exponent - lead_exponent);
+            for &(c, e) in &divisor[1..] {
+                let entry = remainder.entry(e + q.1).or_insert_with(T::zero);
+                *entry = *entry - q.0 * c;
+                if *entry == T::zero() {
+                    remainder.remove(&(e + q.1));
+                }
+            }
+            // The leading term cancels by construction, remove it to avoid rounding residue.
+            remainder.remove(&exponent);
+            quotient.push(q);
+        }
+
+        let remainder = remainder.into_iter().map(|(e, c)| (c, e)).collect();
+        Ok((quotient.into_iter().collect(), remainder))
+    }
+
+    /// Greatest common divisor by the Euclidean algorithm, scaled to a leading coefficient of one.
+    ///
+    /// The result is exact for exact coefficient types like [`Rational`](crate::Rational). With
+    /// floating point coefficients a remainder only ends the algorithm if it cancels exactly.
+    pub fn gcd(&self, other: &Polynom<T>) -> Polynom<T> {
+        let mut a: Polynom<T> = self.terms().collect();
+        let mut b: Polynom<T> = other.terms().collect();
+        while let Ok((_, remainder)) = a.div_rem(&b) {
+            a = std::mem::replace(&mut b, remainder);
+        }
+        match a.terms().next() {
+            Some((lead_coefficient, _)) => {
+                a.terms().map(|(c, e)| (c / lead_coefficient, e)).collect()
+            }
+            None => a,
+        }
+    }
+}
+
+impl<'a, T: Field> Div<&'a Polynom<T>> for &'a Polynom<T> {
+    type Output = Polynom<T>;
+
+    /// # Panics
+    ///
+    /// Panics if `rhs` has no terms.
+    fn div(self, rhs: &'a Polynom<T>) -> Polynom<T> {
+        self.div_rem(rhs)
+            .expect("attempt to divide by an empty polynom")
+            .0
+    }
+}
+
+impl<'a, T: Field> Rem<&'a Polynom<T>> for &'a Polynom<T> {
+    type Output = Polynom<T>;
+
+    /// # Panics
+    ///
+    /// Panics if `rhs` has no terms.
+    fn rem(self, rhs: &'a Polynom<T>) -> Polynom<T> {
+        self.div_rem(rhs)
+            .expect("attempt to calculate the remainder with an empty divisor")
+            .1
+    }
+}
+
+forward_binop!(Div, div, DivAssign, div_assign, [T: Field] Polynom<T>);
+forward_binop!(Rem, rem, RemAssign, rem_assign, [T: Field] Polynom<T>);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn div_rem_polynoms() {
+        // given:
+        let under_test = Polynom::new()
+            .add_term(1., 3)
+            .add_term(-2., 2)
+            .add_term(-11., 1)
+            .add_term(12., 0);
+        let divisor = Polynom::new().add_term(1., 1).add_term(-2., 0);
+
+        // when:
+        let (quotient, remainder) = under_test.div_rem(&divisor).unwrap();
+
+        // then:
+        assert_eq!(quotient.to_string(), "1x^2 -11");
+        assert_eq!(remainder.to_string(), "-10");
+    }
+
+    #[test]
+    fn deflate_by_root() {
+        // given:
+        let under_test = Polynom::new()
+            .add_term(1., 3)
+            .add_term(-2., 2)
+            .add_term(-5., 1)
+            .add_term(6., 0);
+        let factor = Polynom::new().add_term(1., 1).add_term(-3., 0);
+
+        // when:
+        let actual = &under_test / &factor;
+        let remainder = &under_test % &factor;
+
+        // then:
+        assert_eq!(actual.to_string(), "1x^2 + 1x -2");
+        assert_eq!(remainder, Polynom::Empty);
+    }
+
+    #[test]
+    fn div_rem_operators_accept_references_and_values() {
+        // given:
+        let under_test = Polynom::new().add_term(1., 2).add_term(-1., 0);
+        let factor = Polynom::new().add_term(1., 1).add_term(1., 0);
+        let expected = Polynom::new().add_term(1., 1).add_term(-1., 0);
+
+        // when:
+        let mut assigned = under_test.clone();
+        assigned /= &factor;
+        let mut remainder = under_test.clone();
+        remainder %= factor.clone();
+
+        // then:
+        assert_eq!(&under_test / factor.clone(), expected);
+        assert_eq!(under_test.clone() / &factor, expected);
+        assert_eq!(&under_test % factor.clone(), Polynom::Empty);
+        assert_eq!(under_test % &factor, Polynom::Empty);
+        assert_eq!(assigned, expected);
+        assert_eq!(remainder, Polynom::Empty);
+    }
+
+    #[test]
+    fn div_rem_by_empty_polynom() {
+        // given:
+        let under_test = Polynom::new().add_term(1., 1);
+
+        // when:
+        let actual = under_test.div_rem(&Polynom::new());
+
+        // then:
+        assert_eq!(actual, Err(DivisionByZero));
+    }
+
+    #[test]
+    #[should_panic(expected = "attempt to divide by an empty polynom")]
+    fn div_operator_by_empty_polynom() {
+        let _ = Polynom::new().add_term(1., 1) / Polynom::new();
+    }
+}
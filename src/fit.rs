@@ -0,0 +1,289 @@
+use crate::Polynom;
+
+/// A least-squares fit as computed by [`Polynom::fit`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct FitReport {
+    pub polynom: Polynom,
+    /// The residuals `y_i - p(x_i)` in the order of the data points.
+    pub residuals: Vec<f64>,
+    /// The coefficient of determination, the share of the weighted variance of `ys` that is
+    /// explained by the fit.
+    pub r_squared: f64,
+    /// The standard error of the coefficient of `x^k` at index `k`. NaN if there are no more
+    /// points than coefficients, which leaves no degrees of freedom to estimate the noise.
+    pub standard_errors: Vec<f64>,
+}
+
+/// The reasons why [`Polynom::fit`] can fail.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum FitError {
+    /// `xs`, `ys` and the weights do not have the same length.
+    LengthMismatch,
+    /// Fewer points with positive weight than coefficients to fit.
+    NotEnoughPoints { points: usize, coefficients: usize },
+    /// The weight at `index` is negative or not finite.
+    InvalidWeight { index: usize },
+    /// The points do not determine the coefficients, for instance because there are fewer
+    /// distinct `x` values than coefficients.
+    RankDeficient,
+}
+
+impl std::fmt::Display for FitError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            FitError::LengthMismatch => write!(f, "xs, ys and weights differ in length"),
+            FitError::NotEnoughPoints {
+                points,
+                coefficients,
+            } => write!(
+                f,
+                "{} points are not enough to fit {} coefficients",
+                points, coefficients
+            ),
+            FitError::InvalidWeight { index } => {
+                write!(f, "weight {} is negative or not finite", index)
+            }
+            FitError::RankDeficient => write!(f, "the points do not determine the coefficients"),
+        }
+    }
+}
+
+impl std::error::Error for FitError {}
+
+impl Polynom {
+    /// Fits a polynom of the given degree to the points `(xs[i], ys[i])` by weighted least
+    /// squares, minimizing `sum w_i (y_i - p(x_i))^2` with all weights one by default.
+    ///
+    /// The problem is solved with a Householder QR factorization of the weighted Vandermonde
+    /// matrix, which avoids squaring its condition number as the normal equations would.
+    pub fn fit(
+        xs: &[f64],
+        ys: &[f64],
+        degree: usize,
+        weights: Option<&[f64]>,
+    ) -> Result<FitReport, FitError> {
+        let weights = weights.map_or_else(|| vec![1.; xs.len()], <[f64]>::to_vec);
+        if ys.len() != xs.len() || weights.len() != xs.len() {
+            return Err(FitError::LengthMismatch);
+        }
+        if let Some(index) = weights.iter().position(|w| !w.is_finite() || *w < 0.) {
+            return Err(FitError::InvalidWeight { index });
+        }
+        let coefficients = degree + 1;
+        let points = weights.iter().filter(|w| **w > 0.).count();
+        if points < coefficients {
+            return Err(FitError::NotEnoughPoints {
+                points,
+                coefficients,
+            });
+        }
+
+        let mut matrix: Vec<Vec<f64>> = xs
+            .iter()
+            .zip(&weights)
+            .map(|(x, w)| {
+                let scale = w.sqrt();
+                (0..coefficients)
+                    .map(|k| scale * x.powi(k as i32))
+                    .collect()
+            })
+            .collect();
+        let mut rhs: Vec<f64> = ys.iter().zip(&weights).map(|(y, w)| w.sqrt() * y).collect();
+        householder(&mut matrix, &mut rhs, coefficients);
+
+        let largest = (0..coefficients)
+            .map(|k| matrix[k][k].abs())
+            .fold(0., f64::max);
+        let threshold = largest * xs.len() as f64 * f64::EPSILON;
+        if (0..coefficients).any(|k| matrix[k][k].abs() <= threshold) {
+            return Err(FitError::RankDeficient);
+        }
+        let solution = back_substitute(&matrix, &rhs[..coefficients]);
+        let polynom: Polynom = solution
+            .iter()
+            .enumerate()
+            .rev()
+            .map(|(k, c)| (*c, k as i32))
+            .collect();
+
+        let residuals: Vec<f64> = xs
+            .iter()
+            .zip(ys)
+            .map(|(x, y)| y - polynom.eval(*x))
+            .collect();
+        let total_weight: f64 = weights.iter().sum();
+        let mean = ys.iter().zip(&weights).map(|(y, w)| w * y).sum::<f64>() / total_weight;
+        let residual_sum: f64 = residuals.iter().zip(&weights).map(|(r, w)| w * r * r).sum();
+        let total_sum: f64 = ys
+            .iter()
+            .zip(&weights)
+            .map(|(y, w)| w * (y - mean) * (y - mean))
+            .sum();
+        let r_squared = if total_sum == 0. {
+            1.
+        } else {
+            1. - residual_sum / total_sum
+        };
+
+        // The covariance of the coefficients is sigma^2 (R^T R)^-1, so the standard errors are
+        // sigma times the row norms of R^-1.
+        let sigma = if points > coefficients {
+            (residual_sum / (points - coefficients) as f64).sqrt()
+        } else {
+            f64::NAN
+        };
+        let mut squares = vec![0.; coefficients];
+        for k in 0..coefficients {
+            let mut unit = vec![0.; coefficients];
+            unit[k] = 1.;
+            let column = back_substitute(&matrix, &unit);
+            for (square, value) in squares.iter_mut().zip(column) {
+                *square += value * value;
+            }
+        }
+        let standard_errors = squares
+            .into_iter()
+            .map(|square| sigma * square.sqrt())
+            .collect();
+
+        Ok(FitReport {
+            polynom,
+            residuals,
+            r_squared,
+            standard_errors,
+        })
+    }
+}
+
+/// Reduces the first `columns` columns of `matrix` to upper triangular form by Householder
+/// reflections, applying the same reflections to `rhs`.
+fn householder(matrix: &mut [Vec<f64>], rhs: &mut [f64], columns: usize) {
+    let rows = matrix.len();
+    for k in 0..columns {
+        let norm = (k..rows)
+            .map(|i| matrix[i][k] * matrix[i][k])
+            .sum::<f64>()
+            .sqrt();
+        if norm == 0. {
+            continue;
+        }
+        // Reflect onto -sign(a_kk) |a| e_k, which avoids cancellation in v_0.
+        let alpha = -norm.copysign(matrix[k][k]);
+        let mut v: Vec<f64> = (k..rows).map(|i| matrix[i][k]).collect();
+        v[0] -= alpha;
+        let v_norm = v.iter().map(|v| v * v).sum::<f64>();
+        for j in k..columns {
+            let dot: f64 = v.iter().zip(&matrix[k..]).map(|(v, row)| v * row[j]).sum();
+            let factor = 2. * dot / v_norm;
+            for (v, row) in v.iter().zip(&mut matrix[k..]) {
+                row[j] -= factor * v;
+            }
+        }
+        let dot: f64 = v.iter().zip(&rhs[k..]).map(|(v, b)| v * b).sum();
+        let factor = 2. * dot / v_norm;
+        for (v, b) in v.iter().zip(&mut rhs[k..]) {
+            *b -= factor * v;
+        }
+    }
+}
+
+/// Solves `R x = b` for the upper triangular `R` in the leading rows and columns of `matrix`.
+fn back_substitute(matrix: &[Vec<f64>], b: &[f64]) -> Vec<f64> {
+    let n = b.len();
+    let mut x = vec![0.; n];
+    for k in (0..n).rev() {
+        let sum: f64 = (k + 1..n).map(|j| matrix[k][j] * x[j]).sum();
+        x[k] = (b[k] - sum) / matrix[k][k];
+    }
+    x
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use assert_approx_eq::assert_approx_eq;
+
+    #[test]
+    fn fit_recovers_exact_polynom() {
+        // given:
+        let expected = Polynom::new()
+            .add_term(1., 3)
+            .add_term(-2., 2)
+            .add_term(-11., 1)
+            .add_term(12., 0);
+        let xs: Vec<f64> = (-5..=5).map(f64::from).collect();
+        let ys: Vec<f64> = xs.iter().map(|x| expected.eval(*x)).collect();
+
+        // when:
+        let actual = Polynom::fit(&xs, &ys, 3, None).unwrap();
+
+        // then:
+        for ((actual, _), (expected, _)) in actual.polynom.terms().zip(expected.terms()) {
+            assert_approx_eq!(actual, expected, 1e-10);
+        }
+        assert!(actual.residuals.iter().all(|r| r.abs() < 1e-10));
+        assert_approx_eq!(actual.r_squared, 1., 1e-12);
+    }
+
+    #[test]
+    fn fit_straight_line_with_statistics() {
+        // given:
+        let xs = [0., 1., 2., 3., 4.];
+        let ys = [1., 3., 2., 5., 4.];
+
+        // when:
+        let actual = Polynom::fit(&xs, &ys, 1, None).unwrap();
+
+        // then:
+        let terms: Vec<_> = actual.polynom.terms().collect();
+        assert_approx_eq!(terms[0].0, 0.8, 1e-12);
+        assert_approx_eq!(terms[1].0, 1.4, 1e-12);
+        for (actual, expected) in actual.residuals.iter().zip(&[-0.4, 0.8, -1., 1.2, -0.6]) {
+            assert_approx_eq!(actual, expected, 1e-12);
+        }
+        assert_approx_eq!(actual.r_squared, 0.64, 1e-12);
+        assert_approx_eq!(actual.standard_errors[0], 0.72f64.sqrt(), 1e-12);
+        assert_approx_eq!(actual.standard_errors[1], 0.12f64.sqrt(), 1e-12);
+    }
+
+    #[test]
+    fn fit_with_weights_ignores_zero_weights() {
+        // given:
+        let xs = [0., 1., 2., 3., 4.];
+        let ys = [1., 3., 100., 7., 9.];
+        let weights = [1., 1., 0., 1., 1.];
+
+        // when:
+        let actual = Polynom::fit(&xs, &ys, 1, Some(&weights)).unwrap();
+
+        // then:
+        let terms: Vec<_> = actual.polynom.terms().collect();
+        assert_approx_eq!(terms[0].0, 2., 1e-12);
+        assert_approx_eq!(terms[1].0, 1., 1e-12);
+        assert_approx_eq!(actual.residuals[2], 95., 1e-12);
+        assert_approx_eq!(actual.r_squared, 1., 1e-12);
+    }
+
+    #[test]
+    fn fit_rejects_invalid_input() {
+        assert_eq!(
+            Polynom::fit(&[1., 2.], &[1.], 1, None),
+            Err(FitError::LengthMismatch)
+        );
+        assert_eq!(
+            Polynom::fit(&[1., 2.], &[1., 2.], 2, None),
+            Err(FitError::NotEnoughPoints {
+                points: 2,
+                coefficients: 3
+            })
+        );
+        assert_eq!(
+            Polynom::fit(&[1., 2.], &[1., 2.], 1, Some(&[1., -1.])),
+            Err(FitError::InvalidWeight { index: 1 })
+        );
+        assert_eq!(
+            Polynom::fit(&[1., 1., 2.], &[1., 2., 3.], 2, None),
+            Err(FitError::RankDeficient)
+        );
+    }
+}
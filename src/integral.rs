@@ -0,0 +1,108 @@
+use crate::{Field, Polynom};
+
+/// Error returned when integrating a polynom with a term `c x^-1`, whose antiderivative
+/// `c ln|x|` is not a polynom.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LogarithmicTerm<T = f64> {
+    /// The coefficient `c` of the term `c x^-1`.
+    pub coefficient: T,
+}
+
+impl<T: std::fmt::Display> std::fmt::Display for LogarithmicTerm<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "the antiderivative of {}x^-1 is logarithmic, not a polynom",
+            self.coefficient
+        )
+    }
+}
+
+impl<T: std::fmt::Debug + std::fmt::Display> std::error::Error for LogarithmicTerm<T> {}
+
+impl<T: Field> Polynom<T> {
+    /// The antiderivative whose constant term is `constant`, the inverse of
+    /// [`Polynom::differentiate`].
+    pub fn integrate(&self, constant: T) -> Result<Polynom<T>, LogarithmicTerm<T>> {
+        let mut terms = Vec::new();
+        for (coefficient, exponent) in self.terms() {
+            if exponent == -1 {
+                return Err(LogarithmicTerm { coefficient });
+            }
+            terms.push((coefficient / T::from_i32(exponent + 1), exponent + 1));
+        }
+        Ok(terms
+            .into_iter()
+            .collect::<Polynom<T>>()
+            .add_term(constant, 0))
+    }
+
+    /// The definite integral from `a` to `b`.
+    ///
+    /// For terms with negative exponents the interval must not contain `0`, where they have a
+    /// pole.
+    pub fn integrate_between(&self, a: T, b: T) -> Result<T, LogarithmicTerm<T>> {
+        let antiderivative = self.integrate(T::zero())?;
+        Ok(antiderivative.eval(b) - antiderivative.eval(a))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Rational;
+    use assert_approx_eq::assert_approx_eq;
+
+    #[test]
+    fn integrate_is_inverse_of_differentiate() {
+        // given:
+        let under_test = Polynom::new()
+            .add_term(-3., 2)
+            .add_term(4., 1)
+            .add_term(-11., 0)
+            .add_term(2., -3);
+
+        // when:
+        let actual = under_test.integrate(12.).unwrap();
+
+        // then:
+        assert_eq!(actual.to_string(), "-1x^3 + 2x^2 -11x + 12 -1x^-2");
+        assert_eq!(actual.differentiate(), under_test);
+    }
+
+    #[test]
+    fn integrate_between_bounds() {
+        // given:
+        let under_test = Polynom::new().add_term(3., 2).add_term(-1., -2);
+        let exact = Polynom::<Rational>::default()
+            .add_term(Rational::from_integer(1), 2)
+            .add_term(Rational::from_integer(1), 0);
+
+        // when:
+        let actual = under_test.integrate_between(1., 2.).unwrap();
+
+        // then:
+        assert_approx_eq!(actual, 7. - 0.5, 1e-12);
+        assert_eq!(
+            exact.integrate_between(Rational::from_integer(0), Rational::new(1, 2)),
+            Ok(Rational::new(13, 24))
+        );
+    }
+
+    #[test]
+    fn integrate_rejects_logarithmic_terms() {
+        // given:
+        let under_test = Polynom::new().add_term(1., 1).add_term(-2., -1);
+
+        // when:
+        let actual = under_test.integrate(0.);
+
+        // then:
+        assert_eq!(actual, Err(LogarithmicTerm { coefficient: -2. }));
+        assert_eq!(
+            actual.unwrap_err().to_string(),
+            "the antiderivative of -2x^-1 is logarithmic, not a polynom"
+        );
+        assert!(under_test.integrate_between(1., 2.).is_err());
+    }
+}
@@ -0,0 +1,359 @@
+use crate::{Field, Polynom};
+
+/// Error returned when two interpolation points share the same `x`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DuplicateX<T = f64> {
+    pub x: T,
+}
+
+impl<T: std::fmt::Display> std::fmt::Display for DuplicateX<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "more than one interpolation point at x = {}", self.x)
+    }
+}
+
+impl<T: std::fmt::Debug + std::fmt::Display> std::error::Error for DuplicateX<T> {}
+
+/// The reasons why [`Polynom::interpolate_hermite`] can fail.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HermiteError<T = f64> {
+    /// More than one node at `x`.
+    DuplicateX { x: T },
+    /// The node at `x` has an empty list of conditions, so not even a value.
+    NoConditions { x: T },
+}
+
+impl<T: std::fmt::Display> std::fmt::Display for HermiteError<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            HermiteError::DuplicateX { x } => {
+                write!(f, "more than one interpolation node at x = {}", x)
+            }
+            HermiteError::NoConditions { x } => {
+                write!(f, "the interpolation node at x = {} has no conditions", x)
+            }
+        }
+    }
+}
+
+impl<T: std::fmt::Debug + std::fmt::Display> std::error::Error for HermiteError<T> {}
+
+impl<T: Field> Polynom<T> {
+    /// The polynom of lowest degree through all `(x, y)` points, by Lagrange's formula.
+    pub fn interpolate(points: &[(T, T)]) -> Result<Polynom<T>, DuplicateX<T>> {
+        // The node polynom w(x) = (x - x_0)...(x - x_n) with ascending coefficients.
+        let mut node = vec![T::one()];
+        for (x, _) in points {
+            node = multiply_linear(&node, *x);
+        }
+        let mut coefficients = vec![T::zero(); points.len()];
+        for (i, (xi, yi)) in points.iter().enumerate() {
+            let mut denominator = T::one();
+            for (j, (xj, _)) in points.iter().enumerate() {
+                if j != i {
+                    if xi == xj {
+                        return Err(DuplicateX { x: *xi });
+                    }
+                    denominator = denominator * (*xi - *xj);
+                }
+            }
+            // The Lagrange basis polynom is w(x) / (x - x_i) / denominator.
+            let scale = *yi / denominator;
+            for (sum, c) in coefficients.iter_mut().zip(divide_linear(&node, *xi)) {
+                *sum = *sum + scale * c;
+            }
+        }
+        Ok(from_ascending(coefficients))
+    }
+
+    /// The polynom of lowest degree that matches, for every node `(x, [f(x), f'(x), ...])`, the
+    /// value and all given derivatives at `x`.
+    ///
+    /// The coefficients of Newton's form are divided differences over the nodes, each repeated
+    /// once per condition, where a difference over `k + 1` equal nodes is `f^(k)(x) / k!`.
+    pub fn interpolate_hermite(nodes: &[(T, Vec<T>)]) -> Result<Polynom<T>, HermiteError<T>> {
+        for (i, (x, derivatives)) in nodes.iter().enumerate() {
+            if derivatives.is_empty() {
+                return Err(HermiteError::NoConditions { x: *x });
+            }
+            if nodes[..i].iter().any(|(other, _)| other == x) {
+                return Err(HermiteError::DuplicateX { x: *x });
+            }
+        }
+        // Every node repeated once per condition, together with its derivatives.
+        let repeated: Vec<(T, &[T])> = nodes
+            .iter()
+            .flat_map(|(x, derivatives)| derivatives.iter().map(move |_| (*x, &derivatives[..])))
+            .collect();
+        let xs: Vec<T> = repeated.iter().map(|(x, _)| *x).collect();
+        let mut differences: Vec<T> = repeated.iter().map(|(_, d)| d[0]).collect();
+        let mut factorial = T::one();
+        for k in 1..repeated.len() {
+            factorial = factorial * T::from_i32(k as i32);
+            for i in (k..repeated.len()).rev() {
+                differences[i] = if xs[i] == xs[i - k] {
+                    repeated[i].1[k] / factorial
+                } else {
+                    (differences[i] - differences[i - 1]) / (xs[i] - xs[i - k])
+                };
+            }
+        }
+        Ok(newton_form(&differences, &xs))
+    }
+}
+
+/// Builds the interpolating polynom in Newton's form one point at a time.
+///
+/// Every added point costs time linear in the number of points so far, because only the newest
+/// diagonal of the divided difference table is kept.
+#[derive(Debug, Clone, PartialEq)]
+pub struct NewtonInterpolator<T = f64> {
+    xs: Vec<T>,
+    /// The divided differences `f[x_0], f[x_0, x_1], ...`, the coefficients of Newton's form.
+    coefficients: Vec<T>,
+    /// The divided differences `f[x_n], f[x_(n-1), x_n], ...` ending at the newest point.
+    diagonal: Vec<T>,
+}
+
+impl<T: Field> Default for NewtonInterpolator<T> {
+    fn default() -> Self {
+        NewtonInterpolator::new()
+    }
+}
+
+impl<T: Field> NewtonInterpolator<T> {
+    pub fn new() -> Self {
+        NewtonInterpolator {
+            xs: Vec::new(),
+            coefficients: Vec::new(),
+            diagonal: Vec::new(),
+        }
+    }
+
+    /// Adds the point `(x, y)`, which raises the degree of the interpolating polynom by at most
+    /// one.
+    pub fn add_point(&mut self, x: T, y: T) -> Result<(), DuplicateX<T>> {
+        if self.xs.contains(&x) {
+            return Err(DuplicateX { x });
+        }
+        let mut diagonal = Vec::with_capacity(self.diagonal.len() + 1);
+        diagonal.push(y);
+        for (k, previous) in self.diagonal.iter().enumerate() {
+            let xk = self.xs[self.xs.len() - 1 - k];
+            let difference = (diagonal[k] - *previous) / (x - xk);
+            diagonal.push(difference);
+        }
+        self.coefficients.push(diagonal[diagonal.len() - 1]);
+        self.diagonal = diagonal;
+        self.xs.push(x);
+        Ok(())
+    }
+
+    /// The number of points added so far.
+    pub fn len(&self) -> usize {
+        self.xs.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.xs.is_empty()
+    }
+
+    /// Evaluates the interpolating polynom in Newton's form by Horner's scheme.
+    pub fn eval(&self, x: T) -> T {
+        self.coefficients
+            .iter()
+            .zip(&self.xs)
+            .rev()
+            .fold(T::zero(), |sum, (c, xk)| sum * (x - *xk) + *c)
+    }
+
+    /// The interpolating polynom through all points added so far.
+    pub fn polynom(&self) -> Polynom<T> {
+        newton_form(&self.coefficients, &self.xs)
+    }
+}
+
+/// Expands Newton's form `c_0 + c_1 (x - x_0) + c_2 (x - x_0)(x - x_1) + ...`.
+fn newton_form<T: Field>(coefficients: &[T], xs: &[T]) -> Polynom<T> {
+    let mut result = Vec::new();
+    for (c, xk) in coefficients.iter().zip(xs).rev() {
+        result = multiply_linear(&result, *xk);
+        if result.is_empty() {
+            result.push(T::zero());
+        }
+        result[0] = result[0] + *c;
+    }
+    from_ascending(result)
+}
+
+/// Multiplies the polynom with ascending `coefficients` by `x - root`.
+fn multiply_linear<T: Field>(coefficients: &[T], root: T) -> Vec<T> {
+    if coefficients.is_empty() {
+        return Vec::new();
+    }
+    let mut product = vec![T::zero(); coefficients.len() + 1];
+    for (i, c) in coefficients.iter().enumerate() {
+        product[i + 1] = product[i + 1] + *c;
+        product[i] = product[i] - root * *c;
+    }
+    product
+}
+
+/// Divides the polynom with ascending `coefficients` by `x - root` by synthetic division,
+/// dropping the remainder.
+fn divide_linear<T: Field>(coefficients: &[T], root: T) -> Vec<T> {
+    let mut quotient = vec![T::zero(); coefficients.len() - 1];
+    let mut carry = T::zero();
+    for k in (0..quotient.len()).rev() {
+        carry = coefficients[k + 1] + root * carry;
+        quotient[k] = carry;
+    }
+    quotient
+}
+
+fn from_ascending<T: Field>(coefficients: Vec<T>) -> Polynom<T> {
+    coefficients
+        .into_iter()
+        .enumerate()
+        .rev()
+        .map(|(exponent, coefficient)| (coefficient, exponent as i32))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Rational;
+    use assert_approx_eq::assert_approx_eq;
+
+    fn rational_points(points: &[(i128, i128)]) -> Vec<(Rational, Rational)> {
+        points
+            .iter()
+            .map(|(x, y)| (Rational::from_integer(*x), Rational::from_integer(*y)))
+            .collect()
+    }
+
+    #[test]
+    fn interpolate_exercise_sheet() {
+        // given:
+        let expected = Polynom::new()
+            .add_term(1., 3)
+            .add_term(-2., 2)
+            .add_term(-11., 1)
+            .add_term(12., 0);
+        let points: Vec<_> = [-3., 0.5, 1., 4.]
+            .iter()
+            .map(|x| (*x, expected.eval(*x)))
+            .collect();
+
+        // when:
+        let actual = Polynom::interpolate(&points).unwrap();
+
+        // then:
+        for (actual, expected) in actual.terms().zip(expected.terms()) {
+            assert_approx_eq!(actual.0, expected.0, 1e-12);
+            assert_eq!(actual.1, expected.1);
+        }
+        assert_eq!(Polynom::<f64>::interpolate(&[]), Ok(Polynom::Empty));
+    }
+
+    #[test]
+    fn interpolate_rational_points_exactly() {
+        // given:
+        let points = rational_points(&[(0, 1), (1, 3), (2, 7), (-1, 1)]);
+
+        // when:
+        let actual = Polynom::interpolate(&points).unwrap();
+
+        // then:
+        assert_eq!(actual.to_string(), "1x^2 + 1x + 1");
+    }
+
+    #[test]
+    fn newton_interpolator_adds_points_incrementally() {
+        // given:
+        let points = rational_points(&[(2, 5), (-1, 2), (0, 1), (3, 40), (1, -2)]);
+        let mut under_test = NewtonInterpolator::new();
+
+        for n in 1..=points.len() {
+            // when:
+            let (x, y) = points[n - 1];
+            under_test.add_point(x, y).unwrap();
+
+            // then:
+            let expected = Polynom::interpolate(&points[..n]).unwrap();
+            assert_eq!(under_test.len(), n);
+            assert_eq!(under_test.polynom(), expected);
+            for (x, y) in &points[..n] {
+                assert_eq!(under_test.eval(*x), *y);
+            }
+        }
+    }
+
+    #[test]
+    fn interpolate_hermite_smooth_step() {
+        // given: a step from 0 to 1 that starts and ends at rest
+        let zero = Rational::from_integer(0);
+        let one = Rational::from_integer(1);
+        let nodes = [(zero, vec![zero, zero]), (one, vec![one, zero])];
+
+        // when:
+        let actual = Polynom::interpolate_hermite(&nodes).unwrap();
+
+        // then:
+        assert_eq!(actual.to_string(), "-2x^3 + 3x^2");
+        for (x, derivatives) in &nodes {
+            assert_eq!(actual.eval(*x), derivatives[0]);
+            assert_eq!(actual.differentiate().eval(*x), derivatives[1]);
+        }
+    }
+
+    #[test]
+    fn interpolate_hermite_with_higher_derivatives() {
+        // given:
+        let nodes = [
+            (-1f64, vec![2., -1., 4.]),
+            (0.5, vec![1.]),
+            (2., vec![0., 3., -2., 6.]),
+        ];
+
+        // when:
+        let actual = Polynom::interpolate_hermite(&nodes).unwrap();
+
+        // then:
+        assert_eq!(actual.degree(), Some(7));
+        for (x, derivatives) in &nodes {
+            for (k, expected) in derivatives.iter().enumerate() {
+                assert_approx_eq!(actual.nth_derivative(k as u32).eval(*x), *expected, 1e-9);
+            }
+        }
+        assert_eq!(
+            Polynom::interpolate_hermite(&[(1., vec![1.]), (1., vec![1., 0.])]),
+            Err(HermiteError::DuplicateX { x: 1. })
+        );
+        assert_eq!(
+            Polynom::interpolate_hermite(&[(0., vec![]), (1., vec![1., 0.])]),
+            Err(HermiteError::NoConditions { x: 0. })
+        );
+    }
+
+    #[test]
+    fn duplicate_x_values_are_rejected() {
+        // given:
+        let points = [(1., 2.), (3., 4.), (1., 5.)];
+        let mut under_test = NewtonInterpolator::new();
+        under_test.add_point(1., 2.).unwrap();
+
+        // when:
+        let actual = under_test.add_point(1., 5.);
+
+        // then:
+        assert_eq!(actual, Err(DuplicateX { x: 1. }));
+        assert_eq!(under_test.len(), 1);
+        assert_eq!(Polynom::interpolate(&points), Err(DuplicateX { x: 1. }));
+        assert_eq!(
+            actual.unwrap_err().to_string(),
+            "more than one interpolation point at x = 1"
+        );
+    }
+}
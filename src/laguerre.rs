@@ -0,0 +1,217 @@
+use crate::roots::{eval_with_derivative, eval_with_derivatives, sort_roots};
+use crate::{Coefficient, Complex, Polynom, RootError, RootReport};
+
+const TOLERANCE: f64 = 1e-14;
+
+const MAX_ITERATIONS: usize = 200;
+
+const POLISH_ITERATIONS: usize = 20;
+
+/// Roots with an imaginary part this small relative to their magnitude are deflated as real.
+const REAL_TOLERANCE: f64 = 1e-8;
+
+/// Fractional steps taken every tenth iteration to break limit cycles.
+const CYCLE_BREAKING_STEPS: [f64; 8] = [0.5, 0.25, 0.75, 0.13, 0.38, 0.62, 0.88, 1.];
+
+impl Polynom {
+    /// Laguerre's method starting at `start`, which converges to a real or complex root from
+    /// almost any starting point.
+    ///
+    /// Fails with [`RootError::NoRoots`] for a nonzero constant.
+    pub fn find_root_laguerre(
+        &self,
+        start: Complex,
+    ) -> Result<RootReport<Complex>, RootError<Complex>> {
+        let (zero_roots, coefficients) = self.root_coefficients();
+        if coefficients.len() <= 1 {
+            return if zero_roots > 0 {
+                Ok(RootReport {
+                    root: Complex::zero(),
+                    residual: Complex::zero(),
+                    iterations: 0,
+                })
+            } else {
+                Err(RootError::NoRoots)
+            };
+        }
+        let (root, iterations) = laguerre(&coefficients, start, MAX_ITERATIONS)?;
+        Ok(RootReport {
+            root,
+            residual: self.eval_complex(root),
+            iterations,
+        })
+    }
+
+    /// Finds all roots by repeating Laguerre's method on the polynom deflated by every root found
+    /// so far.
+    ///
+    /// Real roots are divided out as linear factors and complex roots together with their
+    /// conjugate as real quadratic factors, so the deflated polynom keeps real coefficients.
+    /// Every root is polished on the original polynom to remove the error accumulated by
+    /// deflation, while the deflation itself continues with the unpolished root.
+    pub fn laguerre_roots(&self) -> Result<Vec<Complex>, RootError<Complex>> {
+        let (zero_roots, original) = self.root_coefficients();
+        let mut deflated = original.clone();
+        let mut roots = vec![Complex::zero(); zero_roots];
+        while deflated.len() > 1 {
+            let (root, _) = laguerre(&deflated, Complex::zero(), MAX_ITERATIONS)?;
+            let polished = match laguerre(&original, root, POLISH_ITERATIONS) {
+                Ok((polished, _)) => polished,
+                Err(_) => root,
+            };
+            if deflated.len() == 2 || root.im.abs() <= REAL_TOLERANCE * root.norm().max(1.) {
+                deflated = deflate(&deflated, &[-root.re, 1.]);
+                roots.push(Complex::from(polished.re));
+            } else {
+                deflated = deflate(&deflated, &[root.norm_sqr(), -2. * root.re, 1.]);
+                roots.push(polished);
+                roots.push(polished.conj());
+            }
+        }
+        sort_roots(&mut roots);
+        Ok(roots)
+    }
+}
+
+/// Synthetic division of the polynom with ascending `coefficients` by the monic `factor`,
+/// dropping the remainder.
+fn deflate(coefficients: &[f64], factor: &[f64]) -> Vec<f64> {
+    let factor_degree = factor.len() - 1;
+    let mut remainder = coefficients.to_vec();
+    let mut quotient = vec![0.; coefficients.len() - factor_degree];
+    for k in (0..quotient.len()).rev() {
+        let q = remainder[k + factor_degree];
+        quotient[k] = q;
+        for (j, f) in factor.iter().enumerate() {
+            remainder[k + j] -= q * f;
+        }
+    }
+    quotient
+}
+
+/// Laguerre's method for the polynom with ascending real `coefficients`.
+fn laguerre(
+    coefficients: &[f64],
+    start: Complex,
+    max_iterations: usize,
+) -> Result<(Complex, usize), RootError<Complex>> {
+    let degree = (coefficients.len() - 1) as f64;
+    let mut z = start;
+    for iteration in 1..=max_iterations {
+        let (value, first, second) = eval_with_derivatives(coefficients, z);
+        if value == Complex::zero() {
+            return Ok((z, iteration - 1));
+        }
+        let g = first / value;
+        let h = g * g - second / value;
+        let root = ((h * degree - g * g) * (degree - 1.)).sqrt();
+        let (plus, minus) = (g + root, g - root);
+        let denominator = if plus.norm() >= minus.norm() {
+            plus
+        } else {
+            minus
+        };
+        let step = if denominator == Complex::zero() {
+            Complex::from_polar(1. + z.norm(), iteration as f64)
+        } else {
+            Complex::from(degree) / denominator
+        };
+        if step.is_nan() {
+            return Err(RootError::NotANumber {
+                iterations: iteration,
+            });
+        }
+        let next = z - step;
+        if step.norm() <= TOLERANCE * next.norm().max(1.) {
+            return Ok((next, iteration));
+        }
+        z = if iteration % 10 == 0 {
+            z - step * CYCLE_BREAKING_STEPS[(iteration / 10) % CYCLE_BREAKING_STEPS.len()]
+        } else {
+            next
+        };
+    }
+    let (residual, _) = eval_with_derivative(coefficients, z);
+    Err(RootError::MaxIterationsReached { x: z, residual })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::roots::assert_roots;
+    use assert_approx_eq::assert_approx_eq;
+
+    #[test]
+    fn find_root_laguerre_from_any_start() {
+        // given:
+        let under_test = Polynom::new().add_term(1., 2).add_term(1., 0);
+
+        // when:
+        let actual = under_test.find_root_laguerre(Complex::zero()).unwrap();
+
+        // then:
+        assert_eq!(actual.root, Complex::I);
+        assert_eq!(actual.residual, Complex::zero());
+        assert_eq!(
+            Polynom::new()
+                .add_term(3., 0)
+                .find_root_laguerre(Complex::zero()),
+            Err(RootError::NoRoots)
+        );
+    }
+
+    #[test]
+    fn laguerre_roots_exercise_sheet() {
+        // given:
+        let under_test = Polynom::new()
+            .add_term(1., 3)
+            .add_term(-2., 2)
+            .add_term(-11., 1)
+            .add_term(12., 0);
+
+        // when:
+        let actual = under_test.laguerre_roots().unwrap();
+
+        // then:
+        assert_roots(&actual, &[(-3., 0.), (1., 0.), (4., 0.)], 1e-10);
+    }
+
+    #[test]
+    fn laguerre_roots_with_complex_pairs() {
+        // given: (x^2 + 2x + 2)(x - 2)(x + 3) x
+        let under_test = Polynom::new()
+            .add_term(1., 5)
+            .add_term(3., 4)
+            .add_term(-2., 3)
+            .add_term(-10., 2)
+            .add_term(-12., 1);
+
+        // when:
+        let actual = under_test.laguerre_roots().unwrap();
+
+        // then:
+        assert_roots(
+            &actual,
+            &[(-3., 0.), (-1., -1.), (-1., 1.), (0., 0.), (2., 0.)],
+            1e-10,
+        );
+    }
+
+    #[test]
+    fn laguerre_roots_of_wilkinson_like_polynom() {
+        // given: (x - 1)(x - 2)...(x - 10)
+        let under_test = (1..=10).fold(Polynom::new().add_term(1., 0), |p, k| {
+            p * Polynom::new().add_term(1., 1).add_term(-(k as f64), 0)
+        });
+
+        // when:
+        let actual = under_test.laguerre_roots().unwrap();
+
+        // then:
+        assert_eq!(actual.len(), 10);
+        for (k, root) in actual.iter().enumerate() {
+            assert_approx_eq!(root.re, (k + 1) as f64, 1e-8);
+            assert_approx_eq!(root.im, 0., 1e-8);
+        }
+    }
+}
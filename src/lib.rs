@@ -1,107 +1,295 @@
-pub enum Polynom {
+mod bounds;
+mod bracket;
+mod builder;
+mod closed_form;
+mod coefficient;
+mod companion;
+mod complex;
+mod dense;
+mod division;
+mod fit;
+mod integral;
+mod interpolation;
+mod laguerre;
+mod multiplicity;
+mod newton;
+mod ops;
+mod orthogonal;
+mod rational;
+mod roots;
+mod sparse;
+mod spline;
+mod sturm;
+mod taylor;
+
+pub use bounds::RootBounds;
+pub use bracket::BracketingMethod;
+pub use builder::PolynomBuilder;
+pub use coefficient::{Coefficient, Field};
+pub use complex::Complex;
+pub use dense::DensePolynom;
+pub use division::DivisionByZero;
+pub use fit::{FitError, FitReport};
+pub use integral::LogarithmicTerm;
+pub use interpolation::{DuplicateX, HermiteError, NewtonInterpolator};
+pub use multiplicity::MultipleRoot;
+pub use newton::{NewtonOptions, RootError, RootReport};
+pub use orthogonal::{InvalidJacobiParameters, OrthogonalFamily};
+pub use rational::Rational;
+pub use roots::{RootsOptions, RootsReport};
+pub use sparse::SparsePolynom;
+pub use spline::{PiecewisePolynom, SplineBoundary, SplineError};
+pub use taylor::NegativeExponent;
+
+#[derive(Default)]
+pub enum Polynom<T = f64> {
+    #[default]
     Empty,
     Full {
-        coefficient: f64,
+        coefficient: T,
         exponent: i32,
-        next: Box<Polynom>,
+        next: Box<Polynom<T>>,
     },
 }
 
-impl std::fmt::Display for Polynom {
+impl<T: Coefficient> std::fmt::Display for Polynom<T> {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        if let Polynom::Full {
-            coefficient,
-            exponent,
-            next,
-        } = self
-        {
+        fmt_terms(f, self.terms())
+    }
+}
+
+/// Writes terms in the format used by the `Display` implementations of all polynom types.
+fn fmt_terms<T: Coefficient>(
+    f: &mut std::fmt::Formatter<'_>,
+    terms: impl Iterator<Item = (T, i32)>,
+) -> std::fmt::Result {
+    for (index, (coefficient, exponent)) in terms.enumerate() {
+        if index > 0 {
+            if coefficient.is_negative() {
+                write!(f, " ")?;
+            } else {
+                write!(f, " + ")?;
+            }
+        }
+        if coefficient.needs_parentheses() {
+            write!(f, "({})", coefficient)?;
+        } else {
             write!(f, "{}", coefficient)?;
-            match exponent {
-                0 => {}
-                1 => write!(f, "x")?,
-                _ => write!(f, "x^{}", exponent)?,
+        }
+        match exponent {
+            0 => {}
+            1 => write!(f, "x")?,
+            _ => write!(f, "x^{}", exponent)?,
+        }
+    }
+    Ok(())
+}
+
+impl<T: Coefficient> std::fmt::Debug for Polynom<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_list().entries(self.terms()).finish()
+    }
+}
+
+// Clone, PartialEq and Drop are implemented by hand, because the derived implementations recurse
+// once per term and overflow the stack for long polynoms.
+
+impl<T: Coefficient> Clone for Polynom<T> {
+    fn clone(&self) -> Self {
+        Polynom::from_terms_in_order(self.terms().collect())
+    }
+}
+
+impl<T: Coefficient> PartialEq for Polynom<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.terms().eq(other.terms())
+    }
+}
+
+impl<T> Drop for Polynom<T> {
+    fn drop(&mut self) {
+        let mut rest = match self {
+            Polynom::Empty => return,
+            Polynom::Full { next, .. } => std::mem::take(&mut **next),
+        };
+        while let Polynom::Full { next, .. } = &mut rest {
+            let following = std::mem::take(&mut **next);
+            rest = following;
+        }
+    }
+}
+
+/// Builds a `Polynom` from `(coefficient, exponent)` pairs.
+///
+/// The terms are sorted by descending exponent, equal exponents are combined and terms with a
+/// zero coefficient are dropped.
+impl<T: Coefficient> std::iter::FromIterator<(T, i32)> for Polynom<T> {
+    fn from_iter<I: IntoIterator<Item = (T, i32)>>(iter: I) -> Self {
+        let mut terms: Vec<(T, i32)> = iter.into_iter().collect();
+        terms.sort_by(|(_, a), (_, b)| b.cmp(a));
+        let mut merged: Vec<(T, i32)> = Vec::with_capacity(terms.len());
+        for (coefficient, exponent) in terms {
+            match merged.last_mut() {
+                Some((c, e)) if *e == exponent => *c = *c + coefficient,
+                _ => merged.push((coefficient, exponent)),
             }
-            if let Polynom::Full { coefficient, .. } = **next {
-                if coefficient < 0. {
-                    write!(f, " {}", next)?;
-                } else {
-                    write!(f, " + {}", next)?;
-                }
+        }
+        merged.retain(|(coefficient, _)| *coefficient != T::zero());
+        Polynom::from_terms_in_order(merged)
+    }
+}
+
+/// Iterator over the `(coefficient, exponent)` pairs of a `Polynom`, see [`Polynom::terms`].
+pub struct Terms<'a, T> {
+    current: &'a Polynom<T>,
+}
+
+impl<'a, T: Copy> Iterator for Terms<'a, T> {
+    type Item = (T, i32);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match self.current {
+            Polynom::Empty => None,
+            Polynom::Full {
+                coefficient,
+                exponent,
+                next,
+            } => {
+                self.current = next;
+                Some((*coefficient, *exponent))
             }
         }
-        Ok(())
     }
 }
 
 impl Polynom {
+    /// Creates an empty `Polynom<f64>`, use [`Polynom::default`] for other coefficient types.
     pub fn new() -> Polynom {
         Polynom::Empty
     }
 
-    pub fn add_term(self, coefficient: f64, exponent: i32) -> Polynom {
-        match self {
-            Polynom::Empty => Polynom::Full {
-                coefficient,
-                exponent,
-                next: Box::new(Polynom::Empty),
-            },
-            Polynom::Full {
-                coefficient: c,
-                exponent: e,
-                next,
-            } => Polynom::Full {
-                coefficient: c,
-                exponent: e,
-                next: Box::new(next.add_term(coefficient, exponent)),
-            },
+    /// Newton's method starting at `guess` with [`NewtonOptions::default`], see
+    /// [`Polynom::find_root_with`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if the iteration fails to converge.
+    pub fn find_root(&self, guess: f64) -> f64 {
+        match self.find_root_with(guess, NewtonOptions::default()) {
+            Ok(report) => report.root,
+            Err(error) => panic!("find_root({}) failed: {}", guess, error),
         }
     }
+}
 
-    pub fn eval(&self, x: f64) -> f64 {
-        match self {
-            Polynom::Empty => 0.,
-            Polynom::Full {
+impl<T: Coefficient> Polynom<T> {
+    /// Builds the list from `terms` as given, without sorting or merging.
+    fn from_terms_in_order(terms: Vec<(T, i32)>) -> Polynom<T> {
+        let mut result = Polynom::Empty;
+        for (coefficient, exponent) in terms.into_iter().rev() {
+            result = Polynom::Full {
                 coefficient,
                 exponent,
-                next,
-            } => coefficient * x.powi(*exponent) + next.eval(x),
+                next: Box::new(result),
+            };
         }
+        result
     }
 
-    pub fn differentiate(&self) -> Polynom {
-        match self {
-            Polynom::Empty => Polynom::Empty,
+    /// Returns an iterator over the `(coefficient, exponent)` pairs in list order.
+    pub fn terms(&self) -> Terms<'_, T> {
+        Terms { current: self }
+    }
+
+    /// Returns the highest exponent, or `None` for a polynom without terms.
+    pub fn degree(&self) -> Option<i32> {
+        self.terms().map(|(_, e)| e).max()
+    }
+
+    /// Converts every coefficient with `f`, for instance from `f64` to [`Complex`].
+    pub fn map_coefficients<U: Coefficient>(&self, f: impl Fn(T) -> U) -> Polynom<U> {
+        self.terms().map(|(c, e)| (f(c), e)).collect()
+    }
+
+    /// Adds a term, keeping the terms sorted by descending exponent. A term with an exponent that
+    /// is already present is merged into it and terms that end up with a zero coefficient are
+    /// removed.
+    ///
+    /// The list has no pointer to its end, so this walks it up to the insertion point. Adding
+    /// terms in ascending order of exponent prepends each of them in constant time, but the usual
+    /// descending order appends every term at the end. Use a [`PolynomBuilder`] to build long
+    /// polynoms term by term.
+    pub fn add_term(mut self, coefficient: T, exponent: i32) -> Polynom<T> {
+        let mut cursor = &mut self;
+        loop {
+            match cursor {
+                Polynom::Full { exponent: e, .. } if *e > exponent => {}
+                _ => break,
+            }
+            if let Polynom::Full { next, .. } = cursor {
+                cursor = next;
+            }
+        }
+        match cursor {
             Polynom::Full {
-                coefficient,
-                exponent,
+                coefficient: c,
+                exponent: e,
                 next,
-            } => {
-                let coefficient = coefficient * *exponent as f64;
-                if coefficient == 0. {
-                    next.differentiate()
-                } else {
-                    Polynom::Full {
-                        coefficient,
-                        exponent: exponent - 1,
-                        next: Box::new(next.differentiate()),
-                    }
+            } if *e == exponent => {
+                *c = *c + coefficient;
+                if *c == T::zero() {
+                    let rest = std::mem::take(&mut **next);
+                    *cursor = rest;
                 }
             }
+            _ if coefficient == T::zero() => {}
+            _ => {
+                let rest = std::mem::take(cursor);
+                *cursor = Polynom::Full {
+                    coefficient,
+                    exponent,
+                    next: Box::new(rest),
+                };
+            }
         }
+        self
     }
 
-    pub fn find_root(&self, guess: f64) -> f64 {
-        fn find_root_impl(poly: &Polynom, derivative: &Polynom, guess: f64) -> f64 {
-            // println!("find_root_impl({}, {}, {})", poly, derivative, guess);
-            let next_guess = guess - poly.eval(guess) / derivative.eval(guess);
-            if (next_guess - guess).abs() < 0.001 {
-                next_guess
-            } else {
-                find_root_impl(poly, derivative, next_guess)
+    /// Brings a polynom into normal form: terms sorted by descending exponent, at most one term
+    /// per exponent and no zero coefficients.
+    ///
+    /// Polynoms built with [`Polynom::add_term`], `collect` or the arithmetic operators are
+    /// always in normal form, this is only needed for polynoms built from the variants directly.
+    pub fn normalize(self) -> Polynom<T> {
+        self.terms().collect()
+    }
+
+    /// Returns whether the polynom is in the normal form produced by [`Polynom::normalize`].
+    pub fn is_normalized(&self) -> bool {
+        let mut previous = None;
+        for (coefficient, exponent) in self.terms() {
+            if coefficient == T::zero() || previous.is_some_and(|p| p <= exponent) {
+                return false;
             }
+            previous = Some(exponent);
         }
-        let derivative = self.differentiate();
-        find_root_impl(self, &derivative, guess)
+        true
+    }
+
+    pub fn eval(&self, x: T) -> T {
+        self.terms()
+            .fold(T::zero(), |sum, (coefficient, exponent)| {
+                sum + coefficient * x.powi(exponent)
+            })
+    }
+
+    pub fn differentiate(&self) -> Polynom<T> {
+        let terms = self
+            .terms()
+            .map(|(coefficient, exponent)| (coefficient * T::from_i32(exponent), exponent - 1))
+            .filter(|(coefficient, _)| *coefficient != T::zero())
+            .collect();
+        Polynom::from_terms_in_order(terms)
     }
 }
 
@@ -158,6 +346,67 @@ mod tests {
         assert_eq!(actual, "1x^3 + 2x^2 -11x + 12");
     }
 
+    #[test]
+    fn add_term_keeps_normal_form() {
+        // given:
+        let under_test = Polynom::new()
+            .add_term(12., 0)
+            .add_term(1., 2)
+            .add_term(0., 5)
+            .add_term(-11., 1)
+            .add_term(3., 2);
+
+        // when:
+        let actual = under_test.to_string();
+
+        // then:
+        assert_eq!(actual, "4x^2 -11x + 12");
+        assert!(under_test.is_normalized());
+        assert_eq!(under_test.degree(), Some(2));
+    }
+
+    #[test]
+    fn add_term_removes_cancelled_terms() {
+        // given:
+        let under_test = Polynom::new().add_term(1., 2).add_term(1., 1);
+
+        // when:
+        let actual = under_test.add_term(-1., 2);
+
+        // then:
+        assert_eq!(actual.to_string(), "1x");
+    }
+
+    #[test]
+    fn normalize_polynoms() {
+        // given:
+        let under_test = Polynom::Full {
+            coefficient: 1.,
+            exponent: 1,
+            next: Box::new(Polynom::Full {
+                coefficient: 0.,
+                exponent: 3,
+                next: Box::new(Polynom::Full {
+                    coefficient: 2.,
+                    exponent: 1,
+                    next: Box::new(Polynom::Full {
+                        coefficient: 5.,
+                        exponent: 2,
+                        next: Box::new(Polynom::Empty),
+                    }),
+                }),
+            }),
+        };
+        assert!(!under_test.is_normalized());
+
+        // when:
+        let actual = under_test.normalize();
+
+        // then:
+        assert_eq!(actual, Polynom::new().add_term(5., 2).add_term(3., 1));
+        assert_eq!(actual.to_string(), "5x^2 + 3x");
+    }
+
     #[test]
     fn eval_polynoms() {
         // given:
@@ -193,6 +442,39 @@ mod tests {
         // then:
         assert_eq!(actual.to_string(), "-3x^2 + 4x -11");
     }
+    #[test]
+    fn million_terms_do_not_overflow_the_stack() {
+        // given:
+        let under_test: Polynom = (0..1_000_000).map(|e| (1., e)).collect();
+
+        // when:
+        let copy = under_test.clone();
+        let derivative = under_test.differentiate();
+        let printed = under_test.to_string();
+        let extended = copy.add_term(2., -1);
+
+        // then:
+        assert_eq!(under_test.eval(1.), 1_000_000.);
+        assert_eq!(derivative.degree(), Some(999_998));
+        assert!(printed.starts_with("1x^999999 + 1x^999998"));
+        assert_ne!(extended, under_test);
+        drop(extended);
+        drop(under_test);
+    }
+
+    #[test]
+    fn add_term_in_ascending_order_prepends() {
+        // given:
+        let terms = 0..100_000;
+
+        // when:
+        let actual = terms.clone().fold(Polynom::new(), |p, e| p.add_term(1., e));
+
+        // then:
+        assert_eq!(actual, terms.map(|e| (1., e)).collect());
+        assert_eq!(actual.degree(), Some(99_999));
+    }
+
     #[test]
     fn find_root_exercise_sheet_first_test() {
         // given
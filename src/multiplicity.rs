@@ -0,0 +1,234 @@
+use crate::newton::{newton, NewtonScalar};
+use crate::roots::sort_roots_by_key;
+use crate::sturm::split_multiple_roots;
+use crate::{Complex, NewtonOptions, Polynom, RootError, RootReport};
+
+/// A derivative counts as vanishing at a root if its value there is this small relative to the
+/// sum of the magnitudes of its terms.
+const VANISHING_TOLERANCE: f64 = 1e-6;
+
+/// A distinct root of a polynom together with the number of times it is repeated.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct MultipleRoot {
+    pub root: Complex,
+    pub multiplicity: usize,
+}
+
+impl Polynom {
+    /// Newton's method applied to `p / p'` instead of `p`, starting at `guess`.
+    ///
+    /// `p / p'` has the same roots as `p`, but all of them simple, so the iteration converges
+    /// quadratically even at multiple roots, where plain Newton's method slows down to linear
+    /// convergence. The reported residual is the value of `p`.
+    pub fn find_multiple_root_with(
+        &self,
+        guess: f64,
+        options: NewtonOptions,
+    ) -> Result<RootReport, RootError> {
+        modified_newton(self, guess, options)
+    }
+
+    /// Finds all distinct complex roots together with their multiplicities, sorted by real part.
+    ///
+    /// The multiple roots are split off by dividing by the gcd of the polynom and its derivative,
+    /// which is computed numerically from the [Sturm sequence](Polynom::sturm_sequence). The
+    /// roots of the quotient are simple and every root of the gcd raises the multiplicity of the
+    /// nearest of them by one, but only if the next derivative of the polynom vanishes there as
+    /// well.
+    ///
+    /// Finally the simple roots are polished on the original polynom by Newton's method in the
+    /// complex plane, keeping the result only if it lowers `|p|`. Multiple roots keep the values
+    /// found on the quotient, where they are simple, because on the original polynom they are
+    /// only determined up to the root of the rounding error.
+    pub fn roots_with_multiplicity(&self) -> Vec<MultipleRoot> {
+        let sequence = self.sturm_sequence();
+        if sequence.is_empty() {
+            return Vec::new();
+        }
+        let polynom = &sequence[0];
+        let complex = polynom.map_coefficients(Complex::from);
+        let magnitudes = polynom.map_coefficients(f64::abs);
+        let (simple, gcd) = split_multiple_roots(&sequence);
+        let mut roots: Vec<MultipleRoot> = simple
+            .roots()
+            .into_iter()
+            .map(|root| MultipleRoot {
+                root,
+                multiplicity: 1,
+            })
+            .collect();
+        for repeated in gcd.roots() {
+            let nearest = roots
+                .iter_mut()
+                .min_by(|a, b| {
+                    (a.root - repeated)
+                        .norm()
+                        .total_cmp(&(b.root - repeated).norm())
+                })
+                .expect("the gcd divides the polynom, so it has no more roots");
+            let derivative = nearest.multiplicity as u32;
+            let value = complex.nth_derivative(derivative).eval(nearest.root);
+            let terms = magnitudes
+                .nth_derivative(derivative)
+                .eval(nearest.root.norm());
+            if value.norm() <= VANISHING_TOLERANCE * terms {
+                nearest.multiplicity += 1;
+            }
+        }
+
+        for root in roots.iter_mut().filter(|root| root.multiplicity == 1) {
+            if let Ok(report) = complex.find_root_with(root.root, NewtonOptions::default()) {
+                if report.residual.norm() < complex.eval(root.root).norm() {
+                    root.root = report.root;
+                }
+            }
+        }
+        sort_roots_by_key(&mut roots, |root| root.root);
+        roots
+    }
+}
+
+/// Newton's method for `u = p / p'`, written as `p p' / (p'^2 - p p'')` to avoid dividing by
+/// `p'`, which vanishes at multiple roots.
+fn modified_newton<X: NewtonScalar>(
+    polynom: &Polynom<X>,
+    guess: X,
+    options: NewtonOptions,
+) -> Result<RootReport<X>, RootError<X>> {
+    let first = polynom.differentiate();
+    let second = first.differentiate();
+    let result = newton(
+        |x| polynom.eval(x) * first.eval(x),
+        |x| {
+            let slope = first.eval(x);
+            slope * slope - polynom.eval(x) * second.eval(x)
+        },
+        guess,
+        options,
+    );
+    match result {
+        Ok(report) => Ok(RootReport {
+            residual: polynom.eval(report.root),
+            ..report
+        }),
+        Err(RootError::MaxIterationsReached { x, .. }) => Err(RootError::MaxIterationsReached {
+            x,
+            residual: polynom.eval(x),
+        }),
+        Err(error) => Err(error),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use assert_approx_eq::assert_approx_eq;
+
+    fn assert_multiple_roots(actual: &[MultipleRoot], expected: &[(f64, f64, usize)]) {
+        assert_eq!(actual.len(), expected.len(), "{:?}", actual);
+        for (actual, (re, im, multiplicity)) in actual.iter().zip(expected) {
+            assert_approx_eq!(actual.root.re, re, 1e-9);
+            assert_approx_eq!(actual.root.im, im, 1e-9);
+            assert_eq!(actual.multiplicity, *multiplicity);
+        }
+    }
+
+    #[test]
+    fn modified_newton_converges_quadratically_at_double_roots() {
+        // given: (x - 1)^2
+        let under_test = Polynom::new()
+            .add_term(1., 2)
+            .add_term(-2., 1)
+            .add_term(1., 0);
+
+        // when:
+        let plain = under_test
+            .find_root_with(3., NewtonOptions::default())
+            .unwrap();
+        let actual = under_test
+            .find_multiple_root_with(3., NewtonOptions::default())
+            .unwrap();
+
+        // then:
+        assert_approx_eq!(actual.root, 1., 1e-12);
+        assert_eq!(actual.residual, under_test.eval(actual.root));
+        assert!(actual.iterations < 10);
+        assert!(plain.iterations > 2 * actual.iterations);
+    }
+
+    #[test]
+    fn roots_with_multiplicity_of_real_roots() {
+        // given: (x - 1)^3 (x + 2)
+        let under_test = Polynom::new()
+            .add_term(1., 4)
+            .add_term(-1., 3)
+            .add_term(-3., 2)
+            .add_term(5., 1)
+            .add_term(-2., 0);
+
+        // when:
+        let actual = under_test.roots_with_multiplicity();
+
+        // then:
+        assert_multiple_roots(&actual, &[(-2., 0., 1), (1., 0., 3)]);
+    }
+
+    #[test]
+    fn roots_with_multiplicity_keeps_multiple_roots_accurate() {
+        // given: (x - 1)^2 (x - 2)^3 (x + 3)
+        let under_test = [1., 1., 2., 2., 2., -3.]
+            .iter()
+            .fold(Polynom::new().add_term(1., 0), |p, root| {
+                p * Polynom::new().add_term(1., 1).add_term(-root, 0)
+            });
+
+        // when:
+        let actual = under_test.roots_with_multiplicity();
+
+        // then:
+        assert_multiple_roots(&actual, &[(-3., 0., 1), (1., 0., 2), (2., 0., 3)]);
+        assert_approx_eq!(actual[2].root.re, 2., 1e-12);
+    }
+
+    #[test]
+    fn roots_with_multiplicity_of_small_but_exact_coefficients() {
+        // given:
+        let close_roots = Polynom::new().add_term(1., 2).add_term(-1e-11, 0);
+        let no_real_roots = Polynom::new().add_term(1., 4).add_term(1e-12, 0);
+
+        // when:
+        let actual = close_roots.roots_with_multiplicity();
+
+        // then:
+        assert_eq!(actual.len(), 2, "{:?}", actual);
+        assert_approx_eq!(actual[0].root.re, -1e-11f64.sqrt(), 1e-18);
+        assert_approx_eq!(actual[1].root.re, 1e-11f64.sqrt(), 1e-18);
+        let actual = no_real_roots.roots_with_multiplicity();
+        assert_eq!(actual.len(), 4, "{:?}", actual);
+        for root in actual {
+            assert_eq!(root.multiplicity, 1);
+            assert_approx_eq!(root.root.norm(), 1e-3, 1e-15);
+        }
+    }
+
+    #[test]
+    fn roots_with_multiplicity_of_complex_and_zero_roots() {
+        // given: (x^2 + 1)^2 and x^2 (x - 3)
+        let complex = Polynom::new()
+            .add_term(1., 4)
+            .add_term(2., 2)
+            .add_term(1., 0);
+        let zero = Polynom::new().add_term(1., 3).add_term(-3., 2);
+
+        // then:
+        assert_multiple_roots(
+            &complex.roots_with_multiplicity(),
+            &[(0., -1., 2), (0., 1., 2)],
+        );
+        assert_multiple_roots(&zero.roots_with_multiplicity(), &[(0., 0., 2), (3., 0., 1)]);
+        assert!(Polynom::new()
+            .add_term(2., 0)
+            .roots_with_multiplicity()
+            .is_empty());
+    }
+}
@@ -0,0 +1,253 @@
+use crate::{Field, Polynom};
+
+/// Configuration of [`Polynom::find_root_with`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct NewtonOptions {
+    /// The iteration stops once a Newton step is smaller than `tolerance * max(1, |x|)`.
+    pub tolerance: f64,
+    /// Maximum number of Newton steps before giving up.
+    pub max_iterations: usize,
+    /// The iteration is considered divergent once `|x|` exceeds this value.
+    pub divergence_limit: f64,
+}
+
+impl Default for NewtonOptions {
+    fn default() -> Self {
+        NewtonOptions {
+            tolerance: 1e-12,
+            max_iterations: 100,
+            divergence_limit: 1e15,
+        }
+    }
+}
+
+/// A root found by one of the root finders together with some diagnostics.
+///
+/// `X` is `f64` for real roots and [`Complex`](crate::Complex) for complex ones.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct RootReport<X = f64> {
+    pub root: X,
+    /// The value of the polynom at `root`.
+    pub residual: X,
+    pub iterations: usize,
+}
+
+/// The reasons why a root finder can fail.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum RootError<X = f64> {
+    /// The derivative vanished at `x`, so no Newton step could be taken.
+    ZeroDerivative { x: X, iterations: usize },
+    /// `|x|` exceeded [`NewtonOptions::divergence_limit`].
+    Diverged { x: X, iterations: usize },
+    /// The iteration produced NaN, for instance because the guess was NaN.
+    NotANumber { iterations: usize },
+    /// No convergence within the iteration limit, `x` is the last iterate.
+    MaxIterationsReached { x: X, residual: X },
+    /// The polynom has the same sign at both ends of the interval passed to
+    /// [`Polynom::find_root_in`].
+    NotBracketed { a: X, b: X },
+    /// The polynom has no real roots, see [`Polynom::find_real_root`].
+    NoRealRoots,
+    /// The polynom is a nonzero constant, which has no roots at all.
+    NoRoots,
+}
+
+impl<X: std::fmt::Display> std::fmt::Display for RootError<X> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            RootError::ZeroDerivative { x, iterations } => write!(
+                f,
+                "derivative is zero at {} after {} iterations",
+                x, iterations
+            ),
+            RootError::Diverged { x, iterations } => {
+                write!(f, "diverged to {} after {} iterations", x, iterations)
+            }
+            RootError::NotANumber { iterations } => {
+                write!(f, "iteration produced NaN after {} iterations", iterations)
+            }
+            RootError::MaxIterationsReached { x, residual } => write!(
+                f,
+                "no convergence within the iteration limit, last iterate {} has residual {}",
+                x, residual
+            ),
+            RootError::NotBracketed { a, b } => write!(
+                f,
+                "the polynom has the same sign at {} and {}, so they do not bracket a root",
+                a, b
+            ),
+            RootError::NoRealRoots => write!(f, "the polynom has no real roots"),
+            RootError::NoRoots => write!(f, "the polynom is a nonzero constant without roots"),
+        }
+    }
+}
+
+impl<X: std::fmt::Debug + std::fmt::Display> std::error::Error for RootError<X> {}
+
+impl Polynom {
+    /// Newton's method starting at `guess`.
+    pub fn find_root_with(
+        &self,
+        guess: f64,
+        options: NewtonOptions,
+    ) -> Result<RootReport, RootError> {
+        let derivative = self.differentiate();
+        newton(|x| self.eval(x), |x| derivative.eval(x), guess, options)
+    }
+}
+
+/// The values Newton's method can iterate on: real and complex numbers.
+pub(crate) trait NewtonScalar: Field {
+    fn magnitude(self) -> f64;
+
+    fn is_nan(self) -> bool;
+}
+
+impl NewtonScalar for f64 {
+    fn magnitude(self) -> f64 {
+        self.abs()
+    }
+
+    fn is_nan(self) -> bool {
+        f64::is_nan(self)
+    }
+}
+
+/// Newton's method for a function `f` with derivative `df`, shared by all polynom types.
+pub(crate) fn newton<X: NewtonScalar>(
+    f: impl Fn(X) -> X,
+    df: impl Fn(X) -> X,
+    guess: X,
+    options: NewtonOptions,
+) -> Result<RootReport<X>, RootError<X>> {
+    let mut x = guess;
+    if x.is_nan() {
+        return Err(RootError::NotANumber { iterations: 0 });
+    }
+    for iterations in 0..options.max_iterations {
+        let value = f(x);
+        if value == X::zero() {
+            return Ok(RootReport {
+                root: x,
+                residual: value,
+                iterations,
+            });
+        }
+        let slope = df(x);
+        if slope == X::zero() {
+            return Err(RootError::ZeroDerivative { x, iterations });
+        }
+        let step = value / slope;
+        let next = x - step;
+        if next.is_nan() {
+            return Err(RootError::NotANumber {
+                iterations: iterations + 1,
+            });
+        }
+        if next.magnitude() > options.divergence_limit {
+            return Err(RootError::Diverged {
+                x: next,
+                iterations: iterations + 1,
+            });
+        }
+        x = next;
+        if step.magnitude() <= options.tolerance * x.magnitude().max(1.) {
+            return Ok(RootReport {
+                root: x,
+                residual: f(x),
+                iterations: iterations + 1,
+            });
+        }
+    }
+    Err(RootError::MaxIterationsReached { x, residual: f(x) })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use assert_approx_eq::assert_approx_eq;
+
+    #[test]
+    fn find_root_with_reports_diagnostics() {
+        // given:
+        let under_test = Polynom::new().add_term(1., 2).add_term(-2., 0);
+
+        // when:
+        let actual = under_test
+            .find_root_with(1., NewtonOptions::default())
+            .unwrap();
+
+        // then:
+        assert_approx_eq!(actual.root, 2f64.sqrt(), 1e-12);
+        assert_approx_eq!(actual.residual, 0., 1e-12);
+        assert!(actual.iterations > 0 && actual.iterations < 10);
+    }
+
+    #[test]
+    fn find_root_with_zero_derivative() {
+        // given:
+        let under_test = Polynom::new().add_term(1., 2).add_term(1., 0);
+
+        // when:
+        let actual = under_test.find_root_with(0., NewtonOptions::default());
+
+        // then:
+        assert_eq!(
+            actual,
+            Err(RootError::ZeroDerivative {
+                x: 0.,
+                iterations: 0
+            })
+        );
+    }
+
+    #[test]
+    fn find_root_with_cycle_exhausts_iterations() {
+        // given: Newton cycles between 0 and 1 for x^3 - 2x + 2
+        let under_test = Polynom::new()
+            .add_term(1., 3)
+            .add_term(-2., 1)
+            .add_term(2., 0);
+        let options = NewtonOptions {
+            max_iterations: 20,
+            ..NewtonOptions::default()
+        };
+
+        // when:
+        let actual = under_test.find_root_with(0., options);
+
+        // then:
+        assert!(matches!(
+            actual,
+            Err(RootError::MaxIterationsReached { .. })
+        ));
+    }
+
+    #[test]
+    fn find_root_with_divergence() {
+        // given: x^2 + 1 has no real root, the first step from near 0 jumps far away
+        let under_test = Polynom::new().add_term(1., 2).add_term(1., 0);
+        let options = NewtonOptions {
+            divergence_limit: 10.,
+            ..NewtonOptions::default()
+        };
+
+        // when:
+        let actual = under_test.find_root_with(1e-3, options);
+
+        // then:
+        assert!(matches!(actual, Err(RootError::Diverged { .. })));
+    }
+
+    #[test]
+    fn find_root_with_nan_guess() {
+        // given:
+        let under_test = Polynom::new().add_term(1., 1);
+
+        // when:
+        let actual = under_test.find_root_with(f64::NAN, NewtonOptions::default());
+
+        // then:
+        assert_eq!(actual, Err(RootError::NotANumber { iterations: 0 }));
+    }
+}
@@ -0,0 +1,177 @@
+use crate::{Coefficient, Polynom};
+use std::ops::{Add, AddAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+
+impl<'a, T: Coefficient> Add<&'a Polynom<T>> for &'a Polynom<T> {
+    type Output = Polynom<T>;
+
+    fn add(self, rhs: &'a Polynom<T>) -> Polynom<T> {
+        self.terms().chain(rhs.terms()).collect()
+    }
+}
+
+impl<'a, T: Coefficient> Sub<&'a Polynom<T>> for &'a Polynom<T> {
+    type Output = Polynom<T>;
+
+    fn sub(self, rhs: &'a Polynom<T>) -> Polynom<T> {
+        self.terms()
+            .chain(rhs.terms().map(|(c, e)| (-c, e)))
+            .collect()
+    }
+}
+
+impl<'a, T: Coefficient> Mul<&'a Polynom<T>> for &'a Polynom<T> {
+    type Output = Polynom<T>;
+
+    fn mul(self, rhs: &'a Polynom<T>) -> Polynom<T> {
+        self.terms()
+            .flat_map(|(c1, e1)| rhs.terms().map(move |(c2, e2)| (c1 * c2, e1 + e2)))
+            .collect()
+    }
+}
+
+impl<T: Coefficient> Neg for &Polynom<T> {
+    type Output = Polynom<T>;
+
+    fn neg(self) -> Polynom<T> {
+        self.terms().map(|(c, e)| (-c, e)).collect()
+    }
+}
+
+impl<T: Coefficient> Neg for Polynom<T> {
+    type Output = Polynom<T>;
+
+    fn neg(self) -> Polynom<T> {
+        -&self
+    }
+}
+
+/// Implements the owned and assigning variants of a binary operator in terms of the
+/// implementation for two references. The generic parameters of the impls go in brackets.
+macro_rules! forward_binop {
+    ($Op:ident, $op:ident, $OpAssign:ident, $op_assign:ident, [$($generics:tt)*] $T:ty) => {
+        impl<$($generics)*> $Op<$T> for $T {
+            type Output = $T;
+
+            fn $op(self, rhs: $T) -> $T {
+                (&self).$op(&rhs)
+            }
+        }
+
+        impl<'a, $($generics)*> $Op<&'a $T> for $T {
+            type Output = $T;
+
+            fn $op(self, rhs: &'a $T) -> $T {
+                (&self).$op(rhs)
+            }
+        }
+
+        impl<'a, $($generics)*> $Op<$T> for &'a $T {
+            type Output = $T;
+
+            fn $op(self, rhs: $T) -> $T {
+                self.$op(&rhs)
+            }
+        }
+
+        impl<$($generics)*> $OpAssign<$T> for $T {
+            fn $op_assign(&mut self, rhs: $T) {
+                *self = (&*self).$op(&rhs);
+            }
+        }
+
+        impl<'a, $($generics)*> $OpAssign<&'a $T> for $T {
+            fn $op_assign(&mut self, rhs: &'a $T) {
+                *self = (&*self).$op(rhs);
+            }
+        }
+    };
+}
+
+pub(crate) use forward_binop;
+
+forward_binop!(Add, add, AddAssign, add_assign, [T: Coefficient] Polynom<T>);
+forward_binop!(Sub, sub, SubAssign, sub_assign, [T: Coefficient] Polynom<T>);
+forward_binop!(Mul, mul, MulAssign, mul_assign, [T: Coefficient] Polynom<T>);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn add_polynoms() {
+        // given:
+        let p = Polynom::new().add_term(1., 3).add_term(2., 1);
+        let q = Polynom::new().add_term(-2., 1).add_term(5., 0);
+
+        // when:
+        let actual = &p + &q;
+
+        // then:
+        assert_eq!(actual.to_string(), "1x^3 + 5");
+    }
+
+    #[test]
+    fn sub_polynoms() {
+        // given:
+        let p = Polynom::new().add_term(1., 2).add_term(3., 0);
+        let q = Polynom::new().add_term(1., 2).add_term(4., 1);
+
+        // when:
+        let actual = p - q;
+
+        // then:
+        assert_eq!(actual.to_string(), "-4x + 3");
+    }
+
+    #[test]
+    fn mul_polynoms() {
+        // given:
+        let p = Polynom::new().add_term(1., 1).add_term(-1., 0);
+        let q = Polynom::new().add_term(1., 1).add_term(1., 0);
+
+        // when:
+        let actual = &p * &q;
+
+        // then:
+        assert_eq!(actual.to_string(), "1x^2 -1");
+    }
+
+    #[test]
+    fn neg_polynom() {
+        // given:
+        let p = Polynom::new().add_term(2., 1).add_term(-3., 0);
+
+        // when:
+        let actual = -p;
+
+        // then:
+        assert_eq!(actual.to_string(), "-2x + 3");
+    }
+
+    #[test]
+    fn mixed_operators() {
+        // given:
+        let p = Polynom::new().add_term(1., 1).add_term(2., 0);
+        let q = Polynom::new().add_term(1., 1).add_term(-2., 0);
+        let r = Polynom::new().add_term(-4., 0);
+
+        // when:
+        let actual = &p * &q - r;
+
+        // then:
+        assert_eq!(actual.to_string(), "1x^2");
+    }
+
+    #[test]
+    fn assign_operators() {
+        // given:
+        let mut actual = Polynom::new().add_term(1., 1);
+
+        // when:
+        actual += Polynom::new().add_term(1., 0);
+        actual *= &Polynom::new().add_term(1., 1).add_term(1., 0);
+
+        // then:
+        assert_eq!(actual.to_string(), "1x^2 + 2x + 1");
+    }
+}
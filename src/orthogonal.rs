@@ -0,0 +1,248 @@
+use crate::Polynom;
+
+/// Error returned for Jacobi parameters that are not both larger than `-1`, for which the weight
+/// `(1 - x)^alpha (1 + x)^beta` is not integrable.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct InvalidJacobiParameters {
+    pub alpha: f64,
+    pub beta: f64,
+}
+
+impl std::fmt::Display for InvalidJacobiParameters {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "Jacobi parameters must be larger than -1, got alpha = {} and beta = {}",
+            self.alpha, self.beta
+        )
+    }
+}
+
+impl std::error::Error for InvalidJacobiParameters {}
+
+/// The classical orthogonal polynomial families, all defined by a three-term recurrence
+/// `p_(k+1) = (a_k x + b_k) p_k - c_k p_(k-1)` starting from `p_0 = 1`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum OrthogonalFamily {
+    /// Chebyshev polynomials of the first kind, `T_n(cos t) = cos(n t)`.
+    ChebyshevT,
+    /// Chebyshev polynomials of the second kind, `U_n(cos t) = sin((n + 1) t) / sin t`.
+    ChebyshevU,
+    /// Legendre polynomials, orthogonal on `[-1, 1]` with weight one.
+    Legendre,
+    /// The physicists' Hermite polynomials `H_n`, orthogonal with weight `e^(-x^2)`.
+    Hermite,
+    /// The probabilists' Hermite polynomials `He_n`, orthogonal with weight `e^(-x^2 / 2)`.
+    HermiteProbabilists,
+    /// Laguerre polynomials, orthogonal on `[0, inf)` with weight `e^(-x)`.
+    Laguerre,
+    /// Jacobi polynomials `P_n^(alpha, beta)`, orthogonal on `[-1, 1]` with weight
+    /// `(1 - x)^alpha (1 + x)^beta`. Both parameters must be larger than `-1`, otherwise
+    /// [`OrthogonalFamily::polynom`] and [`OrthogonalFamily::eval`] fail.
+    Jacobi { alpha: f64, beta: f64 },
+}
+
+impl OrthogonalFamily {
+    /// The polynom of degree `n` of the family, built by the recurrence.
+    pub fn polynom(self, n: u32) -> Result<Polynom, InvalidJacobiParameters> {
+        self.check_parameters()?;
+        let mut previous = Polynom::new();
+        let mut current = Polynom::new().add_term(1., 0);
+        for k in 0..n {
+            let (a, b, c) = self.recurrence(k);
+            let factor = Polynom::new().add_term(a, 1).add_term(b, 0);
+            let next = &(&factor * &current) - &previous.map_coefficients(|v| c * v);
+            previous = std::mem::replace(&mut current, next);
+        }
+        Ok(current)
+    }
+
+    /// Evaluates the polynom of degree `n` at `x` directly by the recurrence, which is more
+    /// accurate than evaluating the expanded polynom, whose coefficients grow quickly and cancel.
+    pub fn eval(self, n: u32, x: f64) -> Result<f64, InvalidJacobiParameters> {
+        self.check_parameters()?;
+        let mut previous = 0.;
+        let mut current = 1.;
+        for k in 0..n {
+            let (a, b, c) = self.recurrence(k);
+            let next = (a * x + b) * current - c * previous;
+            previous = current;
+            current = next;
+        }
+        Ok(current)
+    }
+
+    fn check_parameters(self) -> Result<(), InvalidJacobiParameters> {
+        match self {
+            // Written so that NaN is rejected as well.
+            OrthogonalFamily::Jacobi { alpha, beta } if !(alpha > -1. && beta > -1.) => {
+                Err(InvalidJacobiParameters { alpha, beta })
+            }
+            _ => Ok(()),
+        }
+    }
+
+    /// The coefficients `(a_k, b_k, c_k)` of the step from degree `k` to `k + 1`.
+    fn recurrence(self, k: u32) -> (f64, f64, f64) {
+        let k = f64::from(k);
+        match self {
+            OrthogonalFamily::ChebyshevT if k == 0. => (1., 0., 0.),
+            OrthogonalFamily::ChebyshevT | OrthogonalFamily::ChebyshevU => (2., 0., 1.),
+            OrthogonalFamily::Legendre => ((2. * k + 1.) / (k + 1.), 0., k / (k + 1.)),
+            OrthogonalFamily::Hermite => (2., 0., 2. * k),
+            OrthogonalFamily::HermiteProbabilists => (1., 0., k),
+            OrthogonalFamily::Laguerre => (-1. / (k + 1.), (2. * k + 1.) / (k + 1.), k / (k + 1.)),
+            OrthogonalFamily::Jacobi { alpha, beta } => {
+                if k == 0. {
+                    return ((alpha + beta + 2.) / 2., (alpha - beta) / 2., 0.);
+                }
+                let n = k + 1.;
+                let sum = 2. * n + alpha + beta;
+                let denominator = 2. * n * (n + alpha + beta) * (sum - 2.);
+                (
+                    (sum - 1.) * sum * (sum - 2.) / denominator,
+                    (sum - 1.) * (alpha * alpha - beta * beta) / denominator,
+                    2. * (n + alpha - 1.) * (n + beta - 1.) * sum / denominator,
+                )
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use assert_approx_eq::assert_approx_eq;
+
+    #[test]
+    fn polynoms_of_the_families() {
+        assert_eq!(
+            OrthogonalFamily::ChebyshevT.polynom(4).unwrap().to_string(),
+            "8x^4 -8x^2 + 1"
+        );
+        assert_eq!(
+            OrthogonalFamily::ChebyshevU.polynom(3).unwrap().to_string(),
+            "8x^3 -4x"
+        );
+        assert_eq!(
+            OrthogonalFamily::Legendre.polynom(3).unwrap().to_string(),
+            "2.5x^3 -1.5x"
+        );
+        assert_eq!(
+            OrthogonalFamily::Hermite.polynom(3).unwrap().to_string(),
+            "8x^3 -12x"
+        );
+        assert_eq!(
+            OrthogonalFamily::HermiteProbabilists
+                .polynom(4)
+                .unwrap()
+                .to_string(),
+            "1x^4 -6x^2 + 3"
+        );
+        assert_eq!(
+            OrthogonalFamily::Laguerre.polynom(2).unwrap().to_string(),
+            "0.5x^2 -2x + 1"
+        );
+        assert_eq!(
+            OrthogonalFamily::Legendre.polynom(0).unwrap().to_string(),
+            "1"
+        );
+    }
+
+    #[test]
+    fn jacobi_generalizes_legendre_and_chebyshev() {
+        // given:
+        let legendre = OrthogonalFamily::Jacobi {
+            alpha: 0.,
+            beta: 0.,
+        };
+        let chebyshev = OrthogonalFamily::Jacobi {
+            alpha: -0.5,
+            beta: -0.5,
+        };
+        let general = OrthogonalFamily::Jacobi {
+            alpha: 1.5,
+            beta: 0.5,
+        };
+
+        for n in 0..8 {
+            for x in [-0.9, -0.2, 0.3, 1.] {
+                // then:
+                assert_approx_eq!(
+                    legendre.eval(n, x).unwrap(),
+                    OrthogonalFamily::Legendre.eval(n, x).unwrap(),
+                    1e-12
+                );
+                // P_n^(-1/2, -1/2) is T_n up to the factor P_n^(-1/2, -1/2)(1).
+                let scale = chebyshev.eval(n, 1.).unwrap();
+                assert_approx_eq!(
+                    chebyshev.eval(n, x).unwrap(),
+                    scale * OrthogonalFamily::ChebyshevT.eval(n, x).unwrap(),
+                    1e-12
+                );
+                assert_approx_eq!(
+                    general.polynom(n).unwrap().eval(x),
+                    general.eval(n, x).unwrap(),
+                    1e-12
+                );
+            }
+        }
+        assert_eq!(general.polynom(1).unwrap().to_string(), "2x + 0.5");
+    }
+
+    #[test]
+    fn eval_by_recurrence_is_stable() {
+        // given:
+        let theta: f64 = 0.3;
+
+        // when:
+        let actual = OrthogonalFamily::ChebyshevT.eval(60, theta.cos()).unwrap();
+
+        // then:
+        assert_approx_eq!(actual, (60. * theta).cos(), 1e-12);
+        assert_approx_eq!(
+            OrthogonalFamily::ChebyshevU.eval(60, theta.cos()).unwrap(),
+            (61. * theta).sin() / theta.sin(),
+            1e-10
+        );
+        for family in [
+            OrthogonalFamily::Hermite,
+            OrthogonalFamily::HermiteProbabilists,
+            OrthogonalFamily::Laguerre,
+        ] {
+            assert_approx_eq!(
+                family.polynom(6).unwrap().eval(0.7),
+                family.eval(6, 0.7).unwrap(),
+                1e-9
+            );
+        }
+    }
+
+    #[test]
+    fn jacobi_rejects_invalid_parameters() {
+        // given:
+        let under_test = OrthogonalFamily::Jacobi {
+            alpha: -3.,
+            beta: 0.,
+        };
+        let expected = InvalidJacobiParameters {
+            alpha: -3.,
+            beta: 0.,
+        };
+
+        // then:
+        assert_eq!(under_test.polynom(0), Err(expected));
+        assert_eq!(under_test.eval(0, 0.5), Err(expected));
+        assert_eq!(under_test.eval(2, 0.5), Err(expected));
+        assert_eq!(
+            expected.to_string(),
+            "Jacobi parameters must be larger than -1, got alpha = -3 and beta = 0"
+        );
+        assert!(OrthogonalFamily::Jacobi {
+            alpha: 0.,
+            beta: f64::NAN
+        }
+        .eval(1, 0.5)
+        .is_err());
+    }
+}
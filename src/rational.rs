@@ -0,0 +1,373 @@
+use crate::coefficient::pow_by_squaring;
+use crate::{Coefficient, Field};
+use std::cmp::Ordering;
+use std::convert::TryFrom;
+use std::ops::{Add, Div, Mul, Neg, Sub};
+
+/// An exact fraction of two `i128`s.
+///
+/// Fractions are always kept in lowest terms with a positive denominator, so the derived equality
+/// is exact. The `checked_*` methods return `None` on overflow, the operators panic instead.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Rational {
+    numerator: i128,
+    denominator: i128,
+}
+
+fn gcd(a: i128, b: i128) -> u128 {
+    let (mut a, mut b) = (a.unsigned_abs(), b.unsigned_abs());
+    while b != 0 {
+        let r = a % b;
+        a = b;
+        b = r;
+    }
+    a
+}
+
+impl Rational {
+    /// Creates the fraction `numerator / denominator` in lowest terms.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `denominator` is zero or the normalized fraction does not fit into `i128`.
+    pub fn new(numerator: i128, denominator: i128) -> Rational {
+        assert!(denominator != 0, "denominator must not be zero");
+        Rational::checked_new(numerator, denominator).expect("attempt to normalize with overflow")
+    }
+
+    /// Like [`Rational::new`], but returns `None` instead of panicking.
+    pub fn checked_new(numerator: i128, denominator: i128) -> Option<Rational> {
+        if denominator == 0 {
+            return None;
+        }
+        // The gcd only overflows i128 if it is 2^127, the magnitude of i128::MIN. The denominator
+        // is then i128::MIN and the numerator i128::MIN or 0, which divide to 1 / 1 or 0 / 1.
+        let divisor = i128::try_from(gcd(numerator, denominator)).unwrap_or(i128::MIN);
+        let (numerator, denominator) = (numerator / divisor, denominator / divisor);
+        if denominator < 0 {
+            Some(Rational {
+                numerator: numerator.checked_neg()?,
+                denominator: denominator.checked_neg()?,
+            })
+        } else {
+            Some(Rational {
+                numerator,
+                denominator,
+            })
+        }
+    }
+
+    pub fn from_integer(n: i128) -> Rational {
+        Rational {
+            numerator: n,
+            denominator: 1,
+        }
+    }
+
+    pub fn numerator(self) -> i128 {
+        self.numerator
+    }
+
+    /// The denominator, which is always positive.
+    pub fn denominator(self) -> i128 {
+        self.denominator
+    }
+
+    pub fn is_integer(self) -> bool {
+        self.denominator == 1
+    }
+
+    pub fn to_f64(self) -> f64 {
+        self.numerator as f64 / self.denominator as f64
+    }
+
+    pub fn checked_add(self, rhs: Rational) -> Option<Rational> {
+        let g = i128::try_from(gcd(self.denominator, rhs.denominator)).ok()?;
+        let numerator = self
+            .numerator
+            .checked_mul(rhs.denominator / g)?
+            .checked_add(rhs.numerator.checked_mul(self.denominator / g)?)?;
+        let denominator = (self.denominator / g).checked_mul(rhs.denominator)?;
+        Rational::checked_new(numerator, denominator)
+    }
+
+    pub fn checked_sub(self, rhs: Rational) -> Option<Rational> {
+        self.checked_add(rhs.checked_neg()?)
+    }
+
+    pub fn checked_mul(self, rhs: Rational) -> Option<Rational> {
+        let g1 = i128::try_from(gcd(self.numerator, rhs.denominator)).ok()?;
+        let g2 = i128::try_from(gcd(rhs.numerator, self.denominator)).ok()?;
+        let numerator = (self.numerator / g1).checked_mul(rhs.numerator / g2)?;
+        let denominator = (self.denominator / g2).checked_mul(rhs.denominator / g1)?;
+        Rational::checked_new(numerator, denominator)
+    }
+
+    /// Returns `None` on overflow or if `rhs` is zero.
+    pub fn checked_div(self, rhs: Rational) -> Option<Rational> {
+        self.checked_mul(rhs.checked_recip()?)
+    }
+
+    pub fn checked_neg(self) -> Option<Rational> {
+        Some(Rational {
+            numerator: self.numerator.checked_neg()?,
+            denominator: self.denominator,
+        })
+    }
+
+    /// Returns `1 / self`, or `None` if `self` is zero or the result overflows.
+    pub fn checked_recip(self) -> Option<Rational> {
+        Rational::checked_new(self.denominator, self.numerator)
+    }
+
+    /// # Panics
+    ///
+    /// Panics if `self` is zero.
+    pub fn recip(self) -> Rational {
+        assert!(self.numerator != 0, "attempt to divide by zero");
+        self.checked_recip()
+            .expect("attempt to calculate the reciprocal with overflow")
+    }
+}
+
+impl Default for Rational {
+    fn default() -> Self {
+        Rational::from_integer(0)
+    }
+}
+
+impl From<i128> for Rational {
+    fn from(n: i128) -> Self {
+        Rational::from_integer(n)
+    }
+}
+
+impl From<i64> for Rational {
+    fn from(n: i64) -> Self {
+        Rational::from_integer(n.into())
+    }
+}
+
+impl From<i32> for Rational {
+    fn from(n: i32) -> Self {
+        Rational::from_integer(n.into())
+    }
+}
+
+impl std::fmt::Display for Rational {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.denominator == 1 {
+            write!(f, "{}", self.numerator)
+        } else {
+            write!(f, "{}/{}", self.numerator, self.denominator)
+        }
+    }
+}
+
+impl Ord for Rational {
+    /// Compares by continued fraction expansion, which cannot overflow.
+    fn cmp(&self, other: &Self) -> Ordering {
+        let (mut a, mut b) = (self.numerator, self.denominator);
+        let (mut c, mut d) = (other.numerator, other.denominator);
+        let mut reversed = false;
+        loop {
+            let (q1, r1) = (a.div_euclid(b), a.rem_euclid(b));
+            let (q2, r2) = (c.div_euclid(d), c.rem_euclid(d));
+            let ordering = match (q1.cmp(&q2), r1 == 0, r2 == 0) {
+                (Ordering::Equal, true, true) => Ordering::Equal,
+                (Ordering::Equal, true, false) => Ordering::Less,
+                (Ordering::Equal, false, true) => Ordering::Greater,
+                (Ordering::Equal, false, false) => {
+                    // r1 / b < r2 / d exactly when b / r1 > d / r2
+                    a = b;
+                    b = r1;
+                    c = d;
+                    d = r2;
+                    reversed = !reversed;
+                    continue;
+                }
+                (ordering, _, _) => ordering,
+            };
+            return if reversed {
+                ordering.reverse()
+            } else {
+                ordering
+            };
+        }
+    }
+}
+
+impl PartialOrd for Rational {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Add for Rational {
+    type Output = Rational;
+
+    fn add(self, rhs: Rational) -> Rational {
+        self.checked_add(rhs).expect("attempt to add with overflow")
+    }
+}
+
+impl Sub for Rational {
+    type Output = Rational;
+
+    fn sub(self, rhs: Rational) -> Rational {
+        self.checked_sub(rhs)
+            .expect("attempt to subtract with overflow")
+    }
+}
+
+impl Mul for Rational {
+    type Output = Rational;
+
+    fn mul(self, rhs: Rational) -> Rational {
+        self.checked_mul(rhs)
+            .expect("attempt to multiply with overflow")
+    }
+}
+
+impl Div for Rational {
+    type Output = Rational;
+
+    fn div(self, rhs: Rational) -> Rational {
+        assert!(rhs.numerator != 0, "attempt to divide by zero");
+        self.checked_div(rhs)
+            .expect("attempt to divide with overflow")
+    }
+}
+
+impl Neg for Rational {
+    type Output = Rational;
+
+    fn neg(self) -> Rational {
+        self.checked_neg().expect("attempt to negate with overflow")
+    }
+}
+
+impl Coefficient for Rational {
+    fn zero() -> Self {
+        Rational::from_integer(0)
+    }
+
+    fn one() -> Self {
+        Rational::from_integer(1)
+    }
+
+    fn from_i32(n: i32) -> Self {
+        Rational::from(n)
+    }
+
+    fn is_negative(&self) -> bool {
+        self.numerator < 0
+    }
+
+    fn powi(self, exponent: i32) -> Self {
+        let power = pow_by_squaring(self, exponent.unsigned_abs());
+        if exponent < 0 {
+            power.recip()
+        } else {
+            power
+        }
+    }
+}
+
+impl Field for Rational {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Polynom;
+
+    fn r(numerator: i128, denominator: i128) -> Rational {
+        Rational::new(numerator, denominator)
+    }
+
+    #[test]
+    fn normalize_rationals() {
+        // when:
+        let actual = r(6, -4);
+
+        // then:
+        assert_eq!(actual.numerator(), -3);
+        assert_eq!(actual.denominator(), 2);
+        assert_eq!(actual.to_string(), "-3/2");
+        assert_eq!(r(4, 2).to_string(), "2");
+    }
+
+    #[test]
+    fn rational_arithmetic() {
+        // given:
+        let a = r(1, 3);
+        let b = r(1, 6);
+
+        // then:
+        assert_eq!(a + b, r(1, 2));
+        assert_eq!(a - b, r(1, 6));
+        assert_eq!(a * b, r(1, 18));
+        assert_eq!(a / b, r(2, 1));
+        assert_eq!(-a, r(-1, 3));
+        assert!(b < a);
+        assert!(r(-7, 3) < r(-2, 1));
+        assert_eq!(r(355, 113).cmp(&r(22, 7)), Ordering::Less);
+    }
+
+    #[test]
+    fn detect_overflow() {
+        // given:
+        let big = Rational::from_integer(i128::MAX);
+
+        // then:
+        assert_eq!(big.checked_add(Rational::from_integer(1)), None);
+        assert_eq!(big.checked_mul(r(1, 2)), Some(r(i128::MAX, 2)));
+        assert_eq!(big.checked_mul(r(2, 1)), None);
+        assert_eq!(Rational::from_integer(i128::MIN).checked_neg(), None);
+        assert_eq!(r(1, 2).checked_div(Rational::from_integer(0)), None);
+        assert_eq!(Rational::checked_new(1, 0), None);
+        assert_eq!(Rational::checked_new(i128::MIN, i128::MIN), Some(r(1, 1)));
+        assert_eq!(Rational::checked_new(0, i128::MIN), Some(r(0, 1)));
+    }
+
+    #[test]
+    #[should_panic(expected = "attempt to add with overflow")]
+    fn add_with_overflow() {
+        let _ = Rational::from_integer(i128::MAX) + Rational::from_integer(1);
+    }
+
+    #[test]
+    fn exact_polynom_division() {
+        // given:
+        let under_test = Polynom::<Rational>::default()
+            .add_term(r(1, 1), 3)
+            .add_term(r(-1, 1), 0);
+        let divisor = Polynom::default()
+            .add_term(r(3, 1), 1)
+            .add_term(r(-3, 1), 0);
+
+        // when:
+        let (quotient, remainder) = under_test.div_rem(&divisor).unwrap();
+
+        // then:
+        assert_eq!(quotient.to_string(), "1/3x^2 + 1/3x + 1/3");
+        assert_eq!(remainder, Polynom::Empty);
+        assert_eq!(quotient.eval(r(1, 2)), r(7, 12));
+        assert_eq!(quotient.eval(r(2, 1)).to_string(), "7/3");
+    }
+
+    #[test]
+    fn exact_polynom_gcd() {
+        // given: (x - 1/2)(x + 1)(x - 2) and 2(x - 1/2)(x - 2)(x + 3)
+        let factor = |root: Rational| Polynom::default().add_term(r(1, 1), 1).add_term(-root, 0);
+        let p = &(&factor(r(1, 2)) * &factor(r(-1, 1))) * &factor(r(2, 1));
+        let q = &(&factor(r(1, 2)) * &factor(r(2, 1))) * &factor(r(-3, 1));
+        let q = &q * &Polynom::default().add_term(r(2, 1), 0);
+
+        // when:
+        let actual = p.gcd(&q);
+
+        // then:
+        assert_eq!(actual.to_string(), "1x^2 -5/2x + 1");
+    }
+}
@@ -0,0 +1,277 @@
+use crate::{Coefficient, Complex, DensePolynom, Polynom};
+
+/// Configuration of [`Polynom::roots_with`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct RootsOptions {
+    /// A root counts as converged once its correction is smaller than
+    /// `tolerance * max(1, |root|)` or the value of the polynom there is below the rounding error
+    /// of evaluating it.
+    pub tolerance: f64,
+    /// Maximum number of sweeps over all roots.
+    pub max_iterations: usize,
+}
+
+impl Default for RootsOptions {
+    fn default() -> Self {
+        RootsOptions {
+            tolerance: 1e-12,
+            max_iterations: 500,
+        }
+    }
+}
+
+/// All roots of a polynom as found by [`Polynom::roots_with`] or [`Polynom::companion_roots`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct RootsReport {
+    /// The roots, repeated according to their multiplicity and sorted by real part.
+    pub roots: Vec<Complex>,
+    /// The number of iterations of the method, which are sweeps over all roots for
+    /// [`Polynom::roots_with`] and QR steps for [`Polynom::companion_roots`].
+    pub iterations: usize,
+    /// Whether every root converged within the iteration limit.
+    pub converged: bool,
+}
+
+impl Polynom {
+    /// Finds all complex roots, by [`Polynom::solve_closed_form`] up to degree four and with
+    /// [`RootsOptions::default`] otherwise, see [`Polynom::roots_with`].
+    ///
+    /// Whether the iteration converged is not reported, the roots are the last iterates either
+    /// way. Use [`Polynom::roots_with`] to find out.
+    pub fn roots(&self) -> Vec<Complex> {
+        self.solve_closed_form()
+            .unwrap_or_else(|| self.roots_with(RootsOptions::default()).roots)
+    }
+
+    /// Finds all complex roots simultaneously with the Aberth–Ehrlich iteration.
+    ///
+    /// Every root stops moving once it has converged, while the others keep being corrected.
+    ///
+    /// Terms with negative exponents are handled by multiplying with the smallest power of `x`
+    /// that turns the polynom into an ordinary one, which does not change its nonzero roots.
+    pub fn roots_with(&self, options: RootsOptions) -> RootsReport {
+        let (zero_roots, coefficients) = self.root_coefficients();
+        let (mut roots, iterations, converged) = aberth(&coefficients, options);
+        roots.extend(std::iter::repeat_n(Complex::zero(), zero_roots));
+        sort_roots(&mut roots);
+        RootsReport {
+            roots,
+            iterations,
+            converged,
+        }
+    }
+
+    /// Splits off the root `0` and returns its multiplicity together with the ascending
+    /// coefficients of the remaining factor, whose constant coefficient is nonzero.
+    pub(crate) fn root_coefficients(&self) -> (usize, Vec<f64>) {
+        let dense = DensePolynom::from(self);
+        let coefficients = dense.coefficients();
+        let zero_roots = coefficients.iter().take_while(|c| **c == 0.).count();
+        (zero_roots, coefficients[zero_roots..].to_vec())
+    }
+}
+
+pub(crate) fn sort_roots(roots: &mut [Complex]) {
+    sort_roots_by_key(roots, |root| *root);
+}
+
+/// Sorts by the real and then the imaginary part of the root that `key` returns.
+pub(crate) fn sort_roots_by_key<R>(roots: &mut [R], key: impl Fn(&R) -> Complex) {
+    roots.sort_by(|a, b| {
+        let (a, b) = (key(a), key(b));
+        a.re.total_cmp(&b.re).then(a.im.total_cmp(&b.im))
+    });
+}
+
+/// Asserts that `actual` are the roots `(re, im)` in `expected`, in the same order and up to
+/// `tolerance`.
+#[cfg(test)]
+pub(crate) fn assert_roots(actual: &[Complex], expected: &[(f64, f64)], tolerance: f64) {
+    use assert_approx_eq::assert_approx_eq;
+
+    assert_eq!(actual.len(), expected.len(), "{:?}", actual);
+    for (actual, (re, im)) in actual.iter().zip(expected) {
+        assert_approx_eq!(actual.re, re, tolerance);
+        assert_approx_eq!(actual.im, im, tolerance);
+    }
+}
+
+/// Evaluates the polynom with ascending `coefficients` and its derivative at `z` by Horner's
+/// scheme.
+pub(crate) fn eval_with_derivative(coefficients: &[f64], z: Complex) -> (Complex, Complex) {
+    let (value, first, _) = eval_with_derivatives(coefficients, z);
+    (value, first)
+}
+
+/// Like [`eval_with_derivative`], but also returns the second derivative.
+pub(crate) fn eval_with_derivatives(
+    coefficients: &[f64],
+    z: Complex,
+) -> (Complex, Complex, Complex) {
+    let mut value = Complex::zero();
+    let mut first = Complex::zero();
+    let mut second = Complex::zero();
+    for coefficient in coefficients.iter().rev() {
+        second = second * z + first;
+        first = first * z + value;
+        value = value * z + Complex::from(*coefficient);
+    }
+    (value, first, second * 2.)
+}
+
+/// The size `eps * sum |a_i| r^i` of the rounding error of evaluating the polynom with ascending
+/// `coefficients` at a point of magnitude `r`. Values below it cannot be told apart from zero.
+pub(crate) fn eval_error_bound(coefficients: &[f64], r: f64) -> f64 {
+    let magnitude = coefficients
+        .iter()
+        .rev()
+        .fold(0., |sum, c| sum * r + c.abs());
+    f64::EPSILON * magnitude
+}
+
+/// Returns `(roots, iterations, converged)` for the polynom with ascending `coefficients`.
+fn aberth(coefficients: &[f64], options: RootsOptions) -> (Vec<Complex>, usize, bool) {
+    let degree = coefficients.len().saturating_sub(1);
+    if degree == 0 {
+        return (Vec::new(), 0, true);
+    }
+    // Start on a circle whose radius is the geometric mean of the root magnitudes. The angle
+    // offset keeps the starting points off the real axis, where conjugate roots would be stuck.
+    let radius = (coefficients[0] / coefficients[degree])
+        .abs()
+        .powf(1. / degree as f64);
+    let mut roots: Vec<Complex> = (0..degree)
+        .map(|k| {
+            let angle = 2. * std::f64::consts::PI * k as f64 / degree as f64 + 0.4;
+            Complex::from_polar(radius, angle)
+        })
+        .collect();
+
+    let mut done = vec![false; degree];
+    for iteration in 1..=options.max_iterations {
+        for i in 0..degree {
+            if done[i] {
+                continue;
+            }
+            let z = roots[i];
+            let (value, derivative) = eval_with_derivative(coefficients, z);
+            if value == Complex::zero() {
+                done[i] = true;
+                continue;
+            }
+            let repulsion = roots
+                .iter()
+                .enumerate()
+                .filter(|(j, _)| *j != i)
+                .fold(Complex::zero(), |sum, (_, other)| {
+                    sum + (z - *other).recip()
+                });
+            let denominator = derivative - value * repulsion;
+            if denominator == Complex::zero() {
+                continue;
+            }
+            let correction = value / denominator;
+            roots[i] = z - correction;
+            // A value within the rounding error means that this last correction is as good as
+            // it gets.
+            done[i] = value.norm() <= eval_error_bound(coefficients, z.norm())
+                || correction.norm() <= options.tolerance * roots[i].norm().max(1.);
+        }
+        if done.iter().all(|done| *done) {
+            return (roots, iteration, true);
+        }
+    }
+    (roots, options.max_iterations, false)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use assert_approx_eq::assert_approx_eq;
+
+    #[test]
+    fn roots_exercise_sheet_first_test() {
+        // given:
+        let under_test = Polynom::new()
+            .add_term(1., 3)
+            .add_term(-2., 2)
+            .add_term(-11., 1)
+            .add_term(12., 0);
+
+        // when:
+        let actual = under_test.roots_with(RootsOptions::default());
+
+        // then:
+        assert!(actual.converged);
+        assert_roots(&actual.roots, &[(-3., 0.), (1., 0.), (4., 0.)], 1e-9);
+    }
+
+    #[test]
+    fn roots_of_unity() {
+        // given:
+        let under_test = Polynom::new().add_term(1., 4).add_term(-1., 0);
+
+        // when:
+        let actual = under_test.roots();
+
+        // then:
+        assert_roots(&actual, &[(-1., 0.), (0., -1.), (0., 1.), (1., 0.)], 1e-9);
+    }
+
+    #[test]
+    fn roots_with_zero_and_negative_exponents() {
+        // given:
+        let under_test = Polynom::new().add_term(1., 3).add_term(-1., 1);
+        let laurent = Polynom::new().add_term(1., 1).add_term(-4., -1);
+
+        // when:
+        let actual = under_test.roots();
+
+        // then:
+        assert_roots(&actual, &[(-1., 0.), (0., 0.), (1., 0.)], 1e-9);
+        assert_roots(&laurent.roots(), &[(-2., 0.), (2., 0.)], 1e-9);
+        assert!(Polynom::new().add_term(5., 0).roots().is_empty());
+    }
+
+    #[test]
+    fn roots_of_ill_conditioned_polynom_converge() {
+        // given: (x - 1)(x - 2)...(x - 12)
+        let under_test = (1..=12).fold(Polynom::new().add_term(1., 0), |p, k| {
+            p * Polynom::new().add_term(1., 1).add_term(-f64::from(k), 0)
+        });
+
+        // when:
+        let actual = under_test.roots_with(RootsOptions::default());
+
+        // then:
+        assert!(actual.converged);
+        assert!(actual.iterations < 100, "{}", actual.iterations);
+        for (k, root) in actual.roots.iter().enumerate() {
+            assert_approx_eq!(root.re, (k + 1) as f64, 1e-7);
+            assert_approx_eq!(root.im, 0., 1e-7);
+        }
+    }
+
+    #[test]
+    fn roots_report_missing_convergence() {
+        // given:
+        let under_test = Polynom::new()
+            .add_term(2., 4)
+            .add_term(7., 3)
+            .add_term(6., 2)
+            .add_term(8., 1)
+            .add_term(12., 0);
+        let options = RootsOptions {
+            max_iterations: 1,
+            ..RootsOptions::default()
+        };
+
+        // when:
+        let actual = under_test.roots_with(options);
+
+        // then:
+        assert!(!actual.converged);
+        assert_eq!(actual.iterations, 1);
+        assert_eq!(actual.roots.len(), 4);
+    }
+}
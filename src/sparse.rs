@@ -0,0 +1,249 @@
+use crate::coefficient::pow_by_squaring;
+use crate::ops::forward_binop;
+use crate::{fmt_terms, Polynom};
+use std::collections::BTreeMap;
+use std::ops::{Add, AddAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+
+/// A polynom stored as a map from exponent to coefficient.
+///
+/// Looking up or inserting a term takes logarithmic time and only nonzero coefficients are
+/// stored, which suits polynoms like `x^10000 + 1` with large gaps between exponents.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct SparsePolynom {
+    terms: BTreeMap<i32, f64>,
+}
+
+impl SparsePolynom {
+    pub fn new() -> SparsePolynom {
+        SparsePolynom::default()
+    }
+
+    /// Adds a term, merging it with an existing term of the same exponent.
+    pub fn add_term(mut self, coefficient: f64, exponent: i32) -> SparsePolynom {
+        self.insert_term(coefficient, exponent);
+        self
+    }
+
+    /// Adds a term in place, merging it with an existing term of the same exponent.
+    pub fn insert_term(&mut self, coefficient: f64, exponent: i32) {
+        let entry = self.terms.entry(exponent).or_insert(0.);
+        *entry += coefficient;
+        if *entry == 0. {
+            self.terms.remove(&exponent);
+        }
+    }
+
+    /// Returns the coefficient of `x^exponent`, which is `0` if there is no such term.
+    pub fn coefficient(&self, exponent: i32) -> f64 {
+        self.terms.get(&exponent).copied().unwrap_or(0.)
+    }
+
+    /// Returns the highest exponent, or `None` for a polynom without terms.
+    pub fn degree(&self) -> Option<i32> {
+        self.terms.keys().next_back().copied()
+    }
+
+    /// Returns an iterator over the `(coefficient, exponent)` pairs in descending order of
+    /// exponents.
+    pub fn terms(&self) -> impl Iterator<Item = (f64, i32)> + '_ {
+        self.terms.iter().rev().map(|(e, c)| (*c, *e))
+    }
+
+    /// Evaluates the polynom with Horner's scheme, bridging the gaps between exponents by
+    /// exponentiation by squaring.
+    pub fn eval(&self, x: f64) -> f64 {
+        let mut terms = self.terms();
+        let (mut value, mut previous) = match terms.next() {
+            Some(term) => term,
+            None => return 0.,
+        };
+        for (coefficient, exponent) in terms {
+            value = value * pow_by_squaring(x, (previous - exponent) as u32) + coefficient;
+            previous = exponent;
+        }
+        if previous >= 0 {
+            value * pow_by_squaring(x, previous as u32)
+        } else {
+            value / pow_by_squaring(x, previous.unsigned_abs())
+        }
+    }
+
+    pub fn differentiate(&self) -> SparsePolynom {
+        SparsePolynom {
+            terms: self
+                .terms
+                .iter()
+                .filter(|(e, _)| **e != 0)
+                .map(|(e, c)| (e - 1, c * *e as f64))
+                .collect(),
+        }
+    }
+}
+
+impl std::fmt::Display for SparsePolynom {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        fmt_terms(f, self.terms())
+    }
+}
+
+impl<'a> Add<&'a SparsePolynom> for &'a SparsePolynom {
+    type Output = SparsePolynom;
+
+    fn add(self, rhs: &'a SparsePolynom) -> SparsePolynom {
+        let mut result = self.clone();
+        for (coefficient, exponent) in rhs.terms() {
+            result.insert_term(coefficient, exponent);
+        }
+        result
+    }
+}
+
+impl<'a> Sub<&'a SparsePolynom> for &'a SparsePolynom {
+    type Output = SparsePolynom;
+
+    fn sub(self, rhs: &'a SparsePolynom) -> SparsePolynom {
+        let mut result = self.clone();
+        for (coefficient, exponent) in rhs.terms() {
+            result.insert_term(-coefficient, exponent);
+        }
+        result
+    }
+}
+
+impl<'a> Mul<&'a SparsePolynom> for &'a SparsePolynom {
+    type Output = SparsePolynom;
+
+    fn mul(self, rhs: &'a SparsePolynom) -> SparsePolynom {
+        let mut result = SparsePolynom::new();
+        for (c1, e1) in self.terms() {
+            for (c2, e2) in rhs.terms() {
+                result.insert_term(c1 * c2, e1 + e2);
+            }
+        }
+        result
+    }
+}
+
+impl Neg for &SparsePolynom {
+    type Output = SparsePolynom;
+
+    fn neg(self) -> SparsePolynom {
+        SparsePolynom {
+            terms: self.terms.iter().map(|(e, c)| (*e, -c)).collect(),
+        }
+    }
+}
+
+impl Neg for SparsePolynom {
+    type Output = SparsePolynom;
+
+    fn neg(self) -> SparsePolynom {
+        -&self
+    }
+}
+
+forward_binop!(Add, add, AddAssign, add_assign, [] SparsePolynom);
+forward_binop!(Sub, sub, SubAssign, sub_assign, [] SparsePolynom);
+forward_binop!(Mul, mul, MulAssign, mul_assign, [] SparsePolynom);
+
+impl From<&Polynom> for SparsePolynom {
+    fn from(polynom: &Polynom) -> Self {
+        let mut result = SparsePolynom::new();
+        for (coefficient, exponent) in polynom.terms() {
+            result.insert_term(coefficient, exponent);
+        }
+        result
+    }
+}
+
+impl From<Polynom> for SparsePolynom {
+    fn from(polynom: Polynom) -> Self {
+        SparsePolynom::from(&polynom)
+    }
+}
+
+impl From<&SparsePolynom> for Polynom {
+    fn from(polynom: &SparsePolynom) -> Self {
+        polynom.terms().collect()
+    }
+}
+
+impl From<SparsePolynom> for Polynom {
+    fn from(polynom: SparsePolynom) -> Self {
+        Polynom::from(&polynom)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn lookup_sparse_terms() {
+        // given:
+        let under_test = SparsePolynom::new()
+            .add_term(1., 10000)
+            .add_term(1., 0)
+            .add_term(2., 10000);
+
+        // when:
+        let actual = under_test.coefficient(10000);
+
+        // then:
+        assert_eq!(actual, 3.);
+        assert_eq!(under_test.coefficient(5000), 0.);
+        assert_eq!(under_test.degree(), Some(10000));
+        assert_eq!(under_test.to_string(), "3x^10000 + 1");
+    }
+
+    #[test]
+    fn eval_sparse_polynoms() {
+        // given:
+        let under_test = SparsePolynom::new()
+            .add_term(1., 10000)
+            .add_term(-5., 3)
+            .add_term(2., 2)
+            .add_term(4., -1);
+
+        // when:
+        let actual = under_test.eval(-1.);
+
+        // then:
+        assert_eq!(actual, 4.);
+        assert_eq!(under_test.eval(0.5), 0.5f64.powi(10000) - 0.625 + 0.5 + 8.);
+    }
+
+    #[test]
+    fn sparse_arithmetic() {
+        // given:
+        let p = SparsePolynom::new().add_term(1., 10000).add_term(1., 0);
+        let q = SparsePolynom::new().add_term(1., 10000).add_term(-1., 0);
+
+        // when:
+        let product = &p * &q;
+        let sum = &p + &q;
+        let difference = p - q;
+
+        // then:
+        assert_eq!(product.to_string(), "1x^20000 -1");
+        assert_eq!(sum.to_string(), "2x^10000");
+        assert_eq!(difference.to_string(), "2");
+    }
+
+    #[test]
+    fn convert_polynom_to_sparse_and_back() {
+        // given:
+        let polynom = Polynom::new()
+            .add_term(1., 10000)
+            .add_term(-11., 1)
+            .add_term(0.5, -2);
+
+        // when:
+        let sparse = SparsePolynom::from(&polynom);
+        let actual = Polynom::from(&sparse);
+
+        // then:
+        assert_eq!(actual, polynom);
+        assert_eq!(sparse.differentiate().to_string(), "10000x^9999 -11 -1x^-3");
+    }
+}
@@ -0,0 +1,400 @@
+use crate::{LogarithmicTerm, Polynom};
+use std::cmp::Ordering::Less;
+
+/// A function that is a polynom on every interval between consecutive breakpoints.
+///
+/// Piece `i` applies on `[b_i, b_(i+1)]` and is a polynom in the local variable `x - b_i`,
+/// which keeps its coefficients small however far the breakpoints are from zero.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PiecewisePolynom {
+    breakpoints: Vec<f64>,
+    pieces: Vec<Polynom>,
+}
+
+/// The end conditions of [`PiecewisePolynom::cubic_spline`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum SplineBoundary {
+    /// Zero second derivative at both ends.
+    Natural,
+    /// Prescribed first derivatives at the ends.
+    Clamped { start: f64, end: f64 },
+    /// Continuous third derivative at the second and the second to last point, so the first two
+    /// and the last two pieces are the same cubic.
+    NotAKnot,
+}
+
+/// The reasons why a [`PiecewisePolynom`] cannot be built.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SplineError {
+    /// The numbers of breakpoints and pieces, or of `xs` and `ys`, do not match.
+    LengthMismatch,
+    /// At least two breakpoints are needed.
+    NotEnoughPoints { points: usize },
+    /// The breakpoint at `index` is not larger than the one before it.
+    NotIncreasing { index: usize },
+}
+
+impl std::fmt::Display for SplineError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SplineError::LengthMismatch => write!(f, "the numbers of points do not match"),
+            SplineError::NotEnoughPoints { points } => {
+                write!(f, "{} points are not enough, at least 2 are needed", points)
+            }
+            SplineError::NotIncreasing { index } => {
+                write!(
+                    f,
+                    "breakpoint {} is not larger than the one before it",
+                    index
+                )
+            }
+        }
+    }
+}
+
+impl std::error::Error for SplineError {}
+
+impl PiecewisePolynom {
+    /// Creates a piecewise polynom from strictly increasing breakpoints and one piece fewer, each
+    /// in the local variable `x - b_i`.
+    pub fn new(breakpoints: Vec<f64>, pieces: Vec<Polynom>) -> Result<Self, SplineError> {
+        check_breakpoints(&breakpoints)?;
+        if pieces.len() + 1 != breakpoints.len() {
+            return Err(SplineError::LengthMismatch);
+        }
+        Ok(PiecewisePolynom {
+            breakpoints,
+            pieces,
+        })
+    }
+
+    /// The cubic spline through the points `(xs[i], ys[i])`, whose first and second derivatives
+    /// are continuous, with the given end conditions.
+    ///
+    /// The slopes at the points are the solution of a tridiagonal system. With fewer than four
+    /// points the not-a-knot condition leaves a single parabola or line through all points.
+    pub fn cubic_spline(
+        xs: &[f64],
+        ys: &[f64],
+        boundary: SplineBoundary,
+    ) -> Result<Self, SplineError> {
+        check_breakpoints(xs)?;
+        if ys.len() != xs.len() {
+            return Err(SplineError::LengthMismatch);
+        }
+        let n = xs.len();
+        let h: Vec<f64> = xs.windows(2).map(|w| w[1] - w[0]).collect();
+        let d: Vec<f64> = (0..n - 1).map(|i| (ys[i + 1] - ys[i]) / h[i]).collect();
+
+        let slopes = if boundary == SplineBoundary::NotAKnot && n < 4 {
+            let points: Vec<(f64, f64)> = xs.iter().copied().zip(ys.iter().copied()).collect();
+            let parabola = Polynom::interpolate(&points)
+                .expect("breakpoints are strictly increasing")
+                .differentiate();
+            xs.iter().map(|x| parabola.eval(*x)).collect()
+        } else {
+            // Row i of the system is sub[i] s_(i-1) + diag[i] s_i + sup[i] s_(i+1) = rhs[i].
+            let mut sub = vec![0.; n];
+            let mut diag = vec![0.; n];
+            let mut sup = vec![0.; n];
+            let mut rhs = vec![0.; n];
+            for i in 1..n - 1 {
+                sub[i] = h[i];
+                diag[i] = 2. * (h[i - 1] + h[i]);
+                sup[i] = h[i - 1];
+                rhs[i] = 3. * (h[i] * d[i - 1] + h[i - 1] * d[i]);
+            }
+            let last = n - 1;
+            match boundary {
+                SplineBoundary::Natural => {
+                    diag[0] = 2.;
+                    sup[0] = 1.;
+                    rhs[0] = 3. * d[0];
+                    sub[last] = 1.;
+                    diag[last] = 2.;
+                    rhs[last] = 3. * d[last - 1];
+                }
+                SplineBoundary::Clamped { start, end } => {
+                    diag[0] = 1.;
+                    rhs[0] = start;
+                    diag[last] = 1.;
+                    rhs[last] = end;
+                }
+                SplineBoundary::NotAKnot => {
+                    let width = h[0] + h[1];
+                    diag[0] = h[1];
+                    sup[0] = width;
+                    rhs[0] = ((h[0] + 2. * width) * h[1] * d[0] + h[0] * h[0] * d[1]) / width;
+                    let (h1, h2) = (h[last - 2], h[last - 1]);
+                    let width = h1 + h2;
+                    sub[last] = width;
+                    diag[last] = h1;
+                    rhs[last] =
+                        (h2 * h2 * d[last - 2] + (2. * width + h2) * h1 * d[last - 1]) / width;
+                }
+            }
+            solve_tridiagonal(&sub, &mut diag, &mut sup, &mut rhs);
+            rhs
+        };
+
+        // The cubic Hermite piece through both ends of every interval with the given slopes.
+        let pieces = (0..n - 1)
+            .map(|i| {
+                let (s0, s1) = (slopes[i], slopes[i + 1]);
+                Polynom::new()
+                    .add_term((s0 + s1 - 2. * d[i]) / (h[i] * h[i]), 3)
+                    .add_term((3. * d[i] - 2. * s0 - s1) / h[i], 2)
+                    .add_term(s0, 1)
+                    .add_term(ys[i], 0)
+            })
+            .collect();
+        PiecewisePolynom::new(xs.to_vec(), pieces)
+    }
+
+    pub fn breakpoints(&self) -> &[f64] {
+        &self.breakpoints
+    }
+
+    /// The pieces, each in the local variable `x - b_i` of its interval.
+    pub fn pieces(&self) -> &[Polynom] {
+        &self.pieces
+    }
+
+    /// Evaluates the piece whose interval contains `x`, found by binary search. Outside the
+    /// breakpoints the first or last piece is extrapolated.
+    pub fn eval(&self, x: f64) -> f64 {
+        let index = self
+            .breakpoints
+            .partition_point(|b| *b <= x)
+            .clamp(1, self.pieces.len())
+            - 1;
+        self.pieces[index].eval(x - self.breakpoints[index])
+    }
+
+    pub fn differentiate(&self) -> PiecewisePolynom {
+        PiecewisePolynom {
+            breakpoints: self.breakpoints.clone(),
+            pieces: self.pieces.iter().map(Polynom::differentiate).collect(),
+        }
+    }
+
+    /// The continuous antiderivative whose value at the first breakpoint is `constant`.
+    pub fn integrate(&self, constant: f64) -> Result<PiecewisePolynom, LogarithmicTerm> {
+        let mut value = constant;
+        let mut pieces = Vec::with_capacity(self.pieces.len());
+        for (piece, interval) in self.pieces.iter().zip(self.breakpoints.windows(2)) {
+            let antiderivative = piece.integrate(value)?;
+            value = antiderivative.eval(interval[1] - interval[0]);
+            pieces.push(antiderivative);
+        }
+        Ok(PiecewisePolynom {
+            breakpoints: self.breakpoints.clone(),
+            pieces,
+        })
+    }
+
+    /// Whether the values and the first `derivatives` derivatives of neighbouring pieces agree at
+    /// every inner breakpoint up to `tolerance * max(1, |value|)`.
+    pub fn is_continuous(&self, derivatives: u32, tolerance: f64) -> bool {
+        self.pieces
+            .windows(2)
+            .zip(self.breakpoints.windows(2))
+            .all(|(pieces, interval)| {
+                let left = pieces[0].eval_derivatives(interval[1] - interval[0], derivatives);
+                let right = pieces[1].eval_derivatives(0., derivatives);
+                left.iter()
+                    .zip(&right)
+                    .all(|(l, r)| (l - r).abs() <= tolerance * l.abs().max(1.))
+            })
+    }
+}
+
+fn check_breakpoints(breakpoints: &[f64]) -> Result<(), SplineError> {
+    if breakpoints.len() < 2 {
+        return Err(SplineError::NotEnoughPoints {
+            points: breakpoints.len(),
+        });
+    }
+    // Comparing with NaN gives None, so NaN is rejected as well.
+    let increasing = |i: &usize| breakpoints[i - 1].partial_cmp(&breakpoints[*i]) == Some(Less);
+    match (1..breakpoints.len()).find(|i| !increasing(i)) {
+        Some(index) => Err(SplineError::NotIncreasing { index }),
+        None => Ok(()),
+    }
+}
+
+/// Solves a tridiagonal system by Gaussian elimination with partial pivoting, leaving the
+/// solution in `rhs`.
+///
+/// The natural and clamped systems are diagonally dominant, but the not-a-knot end rows are not,
+/// so rows are swapped whenever the entry below the diagonal is larger. A swap moves an entry
+/// into the second superdiagonal. The systems are nonsingular for strictly increasing
+/// breakpoints, so with pivoting no pivot vanishes.
+fn solve_tridiagonal(sub: &[f64], diag: &mut [f64], sup: &mut [f64], rhs: &mut [f64]) {
+    let n = diag.len();
+    let mut second = vec![0.; n];
+    for i in 0..n - 1 {
+        if diag[i].abs() >= sub[i + 1].abs() {
+            let factor = sub[i + 1] / diag[i];
+            diag[i + 1] -= factor * sup[i];
+            rhs[i + 1] -= factor * rhs[i];
+        } else {
+            // Swap rows i and i + 1 and eliminate with the new row i + 1.
+            let factor = diag[i] / sub[i + 1];
+            diag[i] = sub[i + 1];
+            let below = diag[i + 1];
+            diag[i + 1] = sup[i] - factor * below;
+            if i + 2 < n {
+                second[i] = sup[i + 1];
+                sup[i + 1] *= -factor;
+            }
+            sup[i] = below;
+            let value = rhs[i];
+            rhs[i] = rhs[i + 1];
+            rhs[i + 1] = value - factor * rhs[i + 1];
+        }
+    }
+    rhs[n - 1] /= diag[n - 1];
+    if n > 1 {
+        rhs[n - 2] = (rhs[n - 2] - sup[n - 2] * rhs[n - 1]) / diag[n - 2];
+    }
+    for i in (0..n.saturating_sub(2)).rev() {
+        rhs[i] = (rhs[i] - sup[i] * rhs[i + 1] - second[i] * rhs[i + 2]) / diag[i];
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use assert_approx_eq::assert_approx_eq;
+
+    fn cubic() -> Polynom {
+        Polynom::new()
+            .add_term(1., 3)
+            .add_term(-2., 2)
+            .add_term(-11., 1)
+            .add_term(12., 0)
+    }
+
+    #[test]
+    fn natural_spline_through_three_points() {
+        // given:
+        let xs = [0., 1., 2.];
+        let ys = [0., 1., 0.];
+
+        // when:
+        let actual = PiecewisePolynom::cubic_spline(&xs, &ys, SplineBoundary::Natural).unwrap();
+
+        // then:
+        assert_eq!(actual.pieces()[0].to_string(), "-0.5x^3 + 1.5x");
+        assert_approx_eq!(actual.eval(0.5), 0.6875, 1e-12);
+        assert_approx_eq!(actual.eval(1.5), 0.6875, 1e-12);
+        assert!(actual.is_continuous(2, 1e-12));
+        let second = actual.differentiate().differentiate();
+        assert_approx_eq!(second.eval(0.), 0., 1e-12);
+        assert_approx_eq!(second.eval(2.), 0., 1e-12);
+    }
+
+    #[test]
+    fn clamped_and_not_a_knot_splines_reproduce_cubics() {
+        // given:
+        let expected = cubic();
+        let derivative = expected.differentiate();
+        let xs = [-3., -1.5, 0., 0.5, 2., 4.];
+        let ys: Vec<f64> = xs.iter().map(|x| expected.eval(*x)).collect();
+        let clamped = SplineBoundary::Clamped {
+            start: derivative.eval(-3.),
+            end: derivative.eval(4.),
+        };
+
+        for boundary in [clamped, SplineBoundary::NotAKnot] {
+            // when:
+            let actual = PiecewisePolynom::cubic_spline(&xs, &ys, boundary).unwrap();
+
+            // then:
+            for k in -40..=50 {
+                let x = k as f64 / 10.;
+                assert_approx_eq!(actual.eval(x), expected.eval(x), 1e-9);
+            }
+            assert!(actual.is_continuous(3, 1e-9));
+        }
+    }
+
+    #[test]
+    fn not_a_knot_spline_on_strongly_non_uniform_grid() {
+        // given: the not-a-knot end rows have off-diagonal entries far larger than the diagonal
+        let expected = cubic();
+        let xs = [-3., -2.999, 0., 0.001, 0.002, 4., 4.0001];
+        let ys: Vec<f64> = xs.iter().map(|x| expected.eval(*x)).collect();
+
+        // when:
+        let actual = PiecewisePolynom::cubic_spline(&xs, &ys, SplineBoundary::NotAKnot).unwrap();
+
+        // then:
+        for k in -30..=40 {
+            let x = k as f64 / 10.;
+            assert_approx_eq!(actual.eval(x), expected.eval(x), 1e-7);
+        }
+        assert!(actual.is_continuous(2, 1e-7));
+    }
+
+    #[test]
+    fn not_a_knot_spline_with_few_points() {
+        // given:
+        let parabola = Polynom::new().add_term(2., 2).add_term(-1., 0);
+
+        // when:
+        let actual = PiecewisePolynom::cubic_spline(
+            &[0., 1., 3.],
+            &[-1., 1., 17.],
+            SplineBoundary::NotAKnot,
+        )
+        .unwrap();
+
+        // then:
+        for x in [-1., 0.5, 2., 4.] {
+            assert_approx_eq!(actual.eval(x), parabola.eval(x), 1e-12);
+        }
+    }
+
+    #[test]
+    fn integrate_and_differentiate_pieces() {
+        // given:
+        let under_test = PiecewisePolynom::new(
+            vec![0., 1., 3.],
+            vec![
+                Polynom::new().add_term(2., 1),
+                Polynom::new().add_term(-1., 1).add_term(2., 0),
+            ],
+        )
+        .unwrap();
+
+        // when:
+        let actual = under_test.integrate(5.).unwrap();
+
+        // then:
+        assert!(under_test.is_continuous(0, 1e-12));
+        assert!(!under_test.is_continuous(1, 1e-12));
+        assert!(actual.is_continuous(1, 1e-12));
+        assert_eq!(actual.eval(0.), 5.);
+        assert_eq!(actual.eval(1.), 6.);
+        assert_eq!(actual.eval(3.), 8.);
+        assert_eq!(actual.differentiate(), under_test);
+    }
+
+    #[test]
+    fn invalid_breakpoints_are_rejected() {
+        assert_eq!(
+            PiecewisePolynom::new(vec![0.], Vec::new()),
+            Err(SplineError::NotEnoughPoints { points: 1 })
+        );
+        assert_eq!(
+            PiecewisePolynom::new(vec![0., 1.], Vec::new()),
+            Err(SplineError::LengthMismatch)
+        );
+        assert_eq!(
+            PiecewisePolynom::cubic_spline(&[0., 2., 2.], &[1., 2., 3.], SplineBoundary::Natural),
+            Err(SplineError::NotIncreasing { index: 2 })
+        );
+    }
+}
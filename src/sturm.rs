@@ -0,0 +1,371 @@
+use crate::{DensePolynom, Polynom};
+use std::collections::BTreeMap;
+
+/// Isolating intervals narrower than this, relative to their position, are not split further.
+const ISOLATION_WIDTH: f64 = 1e-10;
+
+const REFINE_TOLERANCE: f64 = 1e-15;
+
+const MAX_REFINE_ITERATIONS: usize = 200;
+
+impl Polynom {
+    /// The Sturm sequence `p, p', -rem(p, p'), ...` of the polynom.
+    ///
+    /// Terms with negative exponents are first removed by multiplying with a power of `x`, which
+    /// does not change the nonzero roots. Every element is scaled to a largest coefficient of
+    /// magnitude one.
+    ///
+    /// Bounds on the rounding errors of all coefficients are carried along the sequence. Remainder
+    /// terms within their bound would vanish in exact arithmetic and are dropped, so a remainder
+    /// that vanishes up to rounding ends the sequence, while one that is merely small does not.
+    pub fn sturm_sequence(&self) -> Vec<Polynom> {
+        let (first, mut previous_error) = scale_with_error(&polynomial_part(self), &Polynom::Empty);
+        if first == Polynom::Empty {
+            return Vec::new();
+        }
+        let (mut current, mut current_error) =
+            scale_with_error(&first.differentiate(), &previous_error.differentiate());
+        let mut sequence = vec![first];
+        while current != Polynom::Empty {
+            let previous = sequence.last().expect("sequence is never empty");
+            let (remainder, error) = match previous.div_rem(&current) {
+                Ok((quotient, remainder)) => {
+                    // Every term of the quotient updates every remainder coefficient once, each
+                    // time rounding the terms that cancel there, which are at most
+                    // |previous| + |quotient| |current|.
+                    let quotient = absolute(&quotient);
+                    let cancelled = &absolute(previous) + &(&quotient * &absolute(&current));
+                    let operations = quotient.terms().count() as f64 + 1.;
+                    let error = &(&previous_error + &(&quotient * &current_error))
+                        + &cancelled.map_coefficients(|c| operations * f64::EPSILON * c);
+                    (chop(-remainder, &error), error)
+                }
+                Err(_) => (Polynom::Empty, Polynom::Empty),
+            };
+            sequence.push(current);
+            previous_error = current_error;
+            (current, current_error) = scale_with_error(&remainder, &error);
+        }
+        sequence
+    }
+
+    /// Counts the distinct real roots in the half-open interval `(a, b]`.
+    pub fn count_real_roots_in(&self, a: f64, b: f64) -> usize {
+        let sequence = self.sturm_sequence();
+        count_in(&sequence, a, b)
+    }
+
+    /// Finds all distinct real roots in ascending order.
+    ///
+    /// The roots are isolated in disjoint intervals with the Sturm sequence and then refined by a
+    /// Newton iteration that falls back to bisection whenever a step would leave the interval.
+    /// Multiple roots are reported once.
+    pub fn real_roots(&self) -> Vec<f64> {
+        let sequence = self.sturm_sequence();
+        let polynom = match sequence.first() {
+            Some(polynom) => polynom,
+            None => return Vec::new(),
+        };
+        // Simple roots change sign and can be refined by Newton's method.
+        let (simple, _) = split_multiple_roots(&sequence);
+        let derivative = simple.differentiate();
+        // Slightly beyond the bound, so that no root lies on the end of an interval.
+        let bound = 1. + polynom.root_bound().best();
+
+        let mut roots = Vec::new();
+        let mut intervals = vec![(-bound, bound)];
+        while let Some((lo, hi)) = intervals.pop() {
+            match count_in(&sequence, lo, hi) {
+                0 => {}
+                1 => roots.push(refine(&sequence, &simple, &derivative, lo, hi)),
+                _ if hi - lo <= ISOLATION_WIDTH * hi.abs().max(lo.abs()).max(1.) => {
+                    // Roots too close to separate make p change sign or vanish up to rounding,
+                    // a count inflated by remainder terms wrongly dropped as rounding errors not.
+                    let mid = (lo + hi) / 2.;
+                    let value = polynom.eval(mid);
+                    if polynom.eval(lo) * polynom.eval(hi) <= 0.
+                        || value.abs() <= eval_error(polynom, mid)
+                    {
+                        roots.push(mid);
+                    }
+                }
+                _ => {
+                    let mid = (lo + hi) / 2.;
+                    intervals.push((lo, mid));
+                    intervals.push((mid, hi));
+                }
+            }
+        }
+        roots.sort_by(f64::total_cmp);
+        roots
+    }
+}
+
+/// Narrows `(-bound, bound]` by bisection on the root count down to an interval `(lo, hi]` that
+/// contains a single distinct real root, or returns `None` if the polynom has no real roots in
+/// the first place.
+pub(crate) fn isolate_real_root(polynom: &Polynom, bound: f64) -> Option<(f64, f64)> {
+    let sequence = polynom.sturm_sequence();
+    let (mut lo, mut hi) = (-bound, bound);
+    let mut count = count_in(&sequence, lo, hi);
+    if count == 0 {
+        return None;
+    }
+    while count > 1 && hi - lo > ISOLATION_WIDTH * hi.abs().max(lo.abs()).max(1.) {
+        let mid = (lo + hi) / 2.;
+        let left = count_in(&sequence, lo, mid);
+        if left > 0 {
+            hi = mid;
+            count = left;
+        } else {
+            lo = mid;
+            count = count_in(&sequence, lo, hi);
+        }
+    }
+    Some((lo, hi))
+}
+
+/// Splits the first element `p` of a nonempty Sturm sequence into `p / gcd(p, p')`, which has
+/// the same roots but all of them simple, and the gcd, which is the last element.
+pub(crate) fn split_multiple_roots(sequence: &[Polynom]) -> (Polynom, Polynom) {
+    let polynom = &sequence[0];
+    let gcd = &sequence[sequence.len() - 1];
+    if gcd.degree() > Some(0) {
+        (scale(&(polynom / gcd)), gcd.clone())
+    } else {
+        (polynom.clone(), gcd.clone())
+    }
+}
+
+/// The polynom multiplied by the smallest power of `x` that removes negative exponents.
+fn polynomial_part(polynom: &Polynom) -> Polynom {
+    let dense = DensePolynom::from(polynom);
+    let shift = dense.lowest_exponent();
+    dense.terms().map(|(c, e)| (c, e - shift)).collect()
+}
+
+fn max_coefficient(polynom: &Polynom) -> f64 {
+    polynom.terms().map(|(c, _)| c.abs()).fold(0., f64::max)
+}
+
+/// Divides by the largest coefficient magnitude, which keeps the signs.
+fn scale(polynom: &Polynom) -> Polynom {
+    let max = max_coefficient(polynom);
+    if max == 0. {
+        Polynom::Empty
+    } else {
+        polynom.terms().map(|(c, e)| (c / max, e)).collect()
+    }
+}
+
+/// Scales like [`scale`] and scales the rounding `error` of the coefficients with them, adding
+/// the rounding of the division.
+fn scale_with_error(polynom: &Polynom, error: &Polynom) -> (Polynom, Polynom) {
+    let max = max_coefficient(polynom);
+    if max == 0. {
+        return (Polynom::Empty, Polynom::Empty);
+    }
+    let scaled = scale(polynom);
+    let error =
+        &error.map_coefficients(|c| c / max) + &scaled.map_coefficients(|c| f64::EPSILON * c.abs());
+    (scaled, error)
+}
+
+fn absolute(polynom: &Polynom) -> Polynom {
+    polynom.map_coefficients(f64::abs)
+}
+
+/// Drops the terms that are not larger than their rounding `error`.
+fn chop(polynom: Polynom, error: &Polynom) -> Polynom {
+    let error: BTreeMap<i32, f64> = error.terms().map(|(c, e)| (e, c)).collect();
+    polynom
+        .terms()
+        .filter(|(c, e)| c.abs() > error.get(e).copied().unwrap_or(0.))
+        .collect()
+}
+
+/// The rounding error `eps * sum |c| |x|^e` of evaluating the polynom at `x`.
+fn eval_error(polynom: &Polynom, x: f64) -> f64 {
+    f64::EPSILON * absolute(polynom).eval(x.abs())
+}
+
+fn sign_changes(sequence: &[Polynom], x: f64) -> usize {
+    let mut changes = 0;
+    let mut previous = 0.;
+    for polynom in sequence {
+        let value = polynom.eval(x);
+        if value != 0. {
+            if previous * value < 0. {
+                changes += 1;
+            }
+            previous = value;
+        }
+    }
+    changes
+}
+
+fn count_in(sequence: &[Polynom], a: f64, b: f64) -> usize {
+    if a >= b {
+        return 0;
+    }
+    sign_changes(sequence, a).saturating_sub(sign_changes(sequence, b))
+}
+
+/// Refines the only distinct root in `(lo, hi]`, which is a simple root of `polynom`.
+fn refine(
+    sequence: &[Polynom],
+    polynom: &Polynom,
+    derivative: &Polynom,
+    mut lo: f64,
+    mut hi: f64,
+) -> f64 {
+    let mut value_lo = polynom.eval(lo);
+    let value_hi = polynom.eval(hi);
+    if value_hi == 0. {
+        return hi;
+    }
+    if value_lo * value_hi > 0. {
+        // Rounding can hide the sign change of a root at the edge, so bisect on the root count.
+        for _ in 0..MAX_REFINE_ITERATIONS {
+            let mid = (lo + hi) / 2.;
+            if mid <= lo || mid >= hi {
+                break;
+            }
+            if count_in(sequence, lo, mid) > 0 {
+                hi = mid;
+            } else {
+                lo = mid;
+            }
+        }
+        return (lo + hi) / 2.;
+    }
+
+    let mut x = (lo + hi) / 2.;
+    for _ in 0..MAX_REFINE_ITERATIONS {
+        let value = polynom.eval(x);
+        if value == 0. {
+            return x;
+        }
+        if value * value_lo < 0. {
+            hi = x;
+        } else {
+            lo = x;
+            value_lo = value;
+        }
+        let newton = x - value / derivative.eval(x);
+        let next = if newton > lo && newton < hi {
+            newton
+        } else {
+            (lo + hi) / 2.
+        };
+        if (next - x).abs() <= REFINE_TOLERANCE * x.abs().max(1.) {
+            return next;
+        }
+        x = next;
+    }
+    x
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use assert_approx_eq::assert_approx_eq;
+
+    fn assert_real_roots(actual: &[f64], expected: &[f64]) {
+        assert_eq!(actual.len(), expected.len(), "{:?}", actual);
+        for (actual, expected) in actual.iter().zip(expected) {
+            assert_approx_eq!(actual, expected, 1e-9);
+        }
+    }
+
+    #[test]
+    fn real_roots_exercise_sheet() {
+        // given:
+        let first = Polynom::new()
+            .add_term(1., 3)
+            .add_term(-2., 2)
+            .add_term(-11., 1)
+            .add_term(12., 0);
+        let second = Polynom::new()
+            .add_term(1., 3)
+            .add_term(-2., 2)
+            .add_term(-5., 1)
+            .add_term(6., 0);
+        let third = Polynom::new()
+            .add_term(2., 4)
+            .add_term(7., 3)
+            .add_term(6., 2)
+            .add_term(8., 1)
+            .add_term(12., 0);
+
+        // then:
+        assert_real_roots(&first.real_roots(), &[-3., 1., 4.]);
+        assert_real_roots(&second.real_roots(), &[-2., 1., 3.]);
+        let third_roots = third.real_roots();
+        assert_eq!(third_roots.len(), 2);
+        assert_approx_eq!(third_roots[0], -2.5943, 0.0001);
+        assert_approx_eq!(third_roots[1], -1.5, 1e-9);
+    }
+
+    #[test]
+    fn count_real_roots_in_intervals() {
+        // given:
+        let under_test = Polynom::new()
+            .add_term(1., 3)
+            .add_term(-2., 2)
+            .add_term(-11., 1)
+            .add_term(12., 0);
+
+        // then:
+        assert_eq!(under_test.count_real_roots_in(-10., 10.), 3);
+        assert_eq!(under_test.count_real_roots_in(0., 10.), 2);
+        assert_eq!(under_test.count_real_roots_in(1., 4.), 1);
+        assert_eq!(under_test.count_real_roots_in(1.5, 3.5), 0);
+    }
+
+    #[test]
+    fn real_roots_with_multiple_roots() {
+        // given: (x - 1)^2 (x + 2)
+        let under_test = Polynom::new()
+            .add_term(1., 3)
+            .add_term(-3., 1)
+            .add_term(2., 0);
+
+        // when:
+        let actual = under_test.real_roots();
+
+        // then:
+        assert_real_roots(&actual, &[-2., 1.]);
+        assert_eq!(under_test.count_real_roots_in(-3., 3.), 2);
+    }
+
+    #[test]
+    fn real_roots_of_small_but_exact_coefficients() {
+        // given:
+        let close_roots = Polynom::new().add_term(1., 2).add_term(-1e-11, 0);
+        let no_real_roots = Polynom::new().add_term(1., 4).add_term(1e-12, 0);
+
+        // when:
+        let actual = close_roots.real_roots();
+
+        // then:
+        assert_eq!(actual.len(), 2, "{:?}", actual);
+        assert_approx_eq!(actual[0], -1e-11f64.sqrt(), 1e-18);
+        assert_approx_eq!(actual[1], 1e-11f64.sqrt(), 1e-18);
+        assert_eq!(close_roots.count_real_roots_in(-1., 1.), 2);
+        assert!(no_real_roots.real_roots().is_empty());
+        assert_eq!(no_real_roots.count_real_roots_in(-1., 1.), 0);
+    }
+
+    #[test]
+    fn real_roots_without_real_roots() {
+        // given:
+        let under_test = Polynom::new().add_term(1., 2).add_term(1., 0);
+        let laurent = Polynom::new().add_term(1., 1).add_term(-4., -1);
+
+        // then:
+        assert!(under_test.real_roots().is_empty());
+        assert_real_roots(&laurent.real_roots(), &[-2., 2.]);
+        assert!(Polynom::new().real_roots().is_empty());
+    }
+}
@@ -0,0 +1,234 @@
+use crate::{Coefficient, Polynom};
+
+/// Error returned when re-expanding a polynom with a term `c x^e` with negative `e`, whose Taylor
+/// series does not terminate.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct NegativeExponent<T = f64> {
+    pub coefficient: T,
+    pub exponent: i32,
+}
+
+impl<T: std::fmt::Display> std::fmt::Display for NegativeExponent<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "the Taylor series of {}x^{} does not terminate",
+            self.coefficient, self.exponent
+        )
+    }
+}
+
+impl<T: std::fmt::Debug + std::fmt::Display> std::error::Error for NegativeExponent<T> {}
+
+impl<T: Coefficient> Polynom<T> {
+    /// The `n`-th derivative, computed term by term without the intermediate derivatives.
+    pub fn nth_derivative(&self, n: u32) -> Polynom<T> {
+        let terms = self
+            .terms()
+            .map(|(coefficient, exponent)| {
+                (
+                    coefficient * falling_factorial(exponent, n),
+                    exponent - n as i32,
+                )
+            })
+            .filter(|(coefficient, _)| *coefficient != T::zero())
+            .collect();
+        Polynom::from_terms_in_order(terms)
+    }
+
+    /// The values `p(x), p'(x), ..., p^(n)(x)` in a single pass over the terms.
+    pub fn eval_derivatives(&self, x: T, n: u32) -> Vec<T> {
+        let mut values = vec![T::zero(); n as usize + 1];
+        for (coefficient, exponent) in self.terms() {
+            let mut factor = coefficient;
+            for (k, value) in values.iter_mut().enumerate() {
+                if factor == T::zero() {
+                    // The term is a polynomial of lower degree than k.
+                    break;
+                }
+                *value = *value + factor * x.powi(exponent - k as i32);
+                factor = factor * T::from_i32(exponent - k as i32);
+            }
+        }
+        values
+    }
+
+    /// Re-expands the polynom around `a`, returning `q` with `p(x) = q(x - a)`.
+    ///
+    /// The coefficients of `q` are the Taylor coefficients `p^(k)(a) / k!`. They are computed by
+    /// repeated synthetic division by `x - a`, which needs neither factorials nor division.
+    pub fn taylor_at(&self, a: T) -> Result<Polynom<T>, NegativeExponent<T>> {
+        let mut degree = None;
+        for (coefficient, exponent) in self.terms() {
+            if exponent < 0 && coefficient != T::zero() {
+                return Err(NegativeExponent {
+                    coefficient,
+                    exponent,
+                });
+            }
+            degree = degree.max(Some(exponent as usize));
+        }
+        let degree = match degree {
+            Some(degree) => degree,
+            None => return Ok(Polynom::Empty),
+        };
+        // Adding up handles polynoms that are not in normal form.
+        let mut coefficients = vec![T::zero(); degree + 1];
+        for (coefficient, exponent) in self.terms().filter(|(_, e)| *e >= 0) {
+            let exponent = exponent as usize;
+            coefficients[exponent] = coefficients[exponent] + coefficient;
+        }
+        for k in 0..degree {
+            for j in (k..degree).rev() {
+                coefficients[j] = coefficients[j] + a * coefficients[j + 1];
+            }
+        }
+        Ok(coefficients
+            .into_iter()
+            .enumerate()
+            .rev()
+            .map(|(exponent, coefficient)| (coefficient, exponent as i32))
+            .collect())
+    }
+}
+
+/// `e (e - 1) ... (e - n + 1)`, the factor of the `n`-th derivative of `x^e`.
+fn falling_factorial<T: Coefficient>(exponent: i32, n: u32) -> T {
+    (0..n as i32).fold(T::one(), |product, k| product * T::from_i32(exponent - k))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Rational;
+    use assert_approx_eq::assert_approx_eq;
+
+    #[test]
+    fn nth_derivative_matches_repeated_differentiation() {
+        // given:
+        let under_test = Polynom::new()
+            .add_term(1., 5)
+            .add_term(-2., 3)
+            .add_term(7., 0)
+            .add_term(3., -1);
+
+        for n in 0..7 {
+            // when:
+            let actual = under_test.nth_derivative(n);
+
+            // then:
+            let expected = (0..n).fold(under_test.clone(), |p, _| p.differentiate());
+            assert_eq!(actual, expected);
+        }
+        assert_eq!(
+            under_test.nth_derivative(3).to_string(),
+            "60x^2 -12 -18x^-4"
+        );
+    }
+
+    #[test]
+    fn eval_derivatives_in_one_pass() {
+        // given:
+        let under_test = Polynom::new()
+            .add_term(1., 3)
+            .add_term(-2., 2)
+            .add_term(-11., 1)
+            .add_term(12., 0)
+            .add_term(1., -1);
+
+        // when:
+        let actual = under_test.eval_derivatives(2., 5);
+
+        // then:
+        assert_eq!(actual.len(), 6);
+        for (n, value) in actual.iter().enumerate() {
+            assert_approx_eq!(*value, under_test.nth_derivative(n as u32).eval(2.), 1e-12);
+        }
+        assert_eq!(
+            Polynom::<i64>::default()
+                .add_term(1, 2)
+                .eval_derivatives(0, 3),
+            vec![0, 0, 2, 0]
+        );
+    }
+
+    #[test]
+    fn taylor_at_shifts_the_polynom() {
+        // given:
+        let under_test = Polynom::<Rational>::default()
+            .add_term(Rational::from_integer(1), 3)
+            .add_term(Rational::from_integer(-2), 2)
+            .add_term(Rational::from_integer(-11), 1)
+            .add_term(Rational::from_integer(12), 0);
+        let a = Rational::new(1, 2);
+
+        // when:
+        let actual = under_test.taylor_at(a).unwrap();
+
+        // then:
+        assert_eq!(actual.degree(), Some(3));
+        for k in -3..=3 {
+            let x = Rational::from_integer(k);
+            assert_eq!(actual.eval(x - a), under_test.eval(x));
+        }
+        assert_eq!(actual.eval(Rational::from_integer(0)), under_test.eval(a));
+        assert_eq!(
+            Polynom::new()
+                .add_term(1., 2)
+                .taylor_at(1.)
+                .unwrap()
+                .to_string(),
+            "1x^2 + 2x + 1"
+        );
+    }
+
+    #[test]
+    fn taylor_at_rejects_negative_exponents() {
+        // given:
+        let under_test = Polynom::new().add_term(1., 2).add_term(3., -1);
+
+        // when:
+        let actual = under_test.taylor_at(1.);
+
+        // then:
+        assert_eq!(
+            actual,
+            Err(NegativeExponent {
+                coefficient: 3.,
+                exponent: -1
+            })
+        );
+        assert_eq!(
+            actual.unwrap_err().to_string(),
+            "the Taylor series of 3x^-1 does not terminate"
+        );
+    }
+
+    #[test]
+    fn taylor_at_adds_up_terms_outside_normal_form() {
+        // given: 1 + 2x + 3x^2 - x as a list that is not in normal form
+        let under_test = Polynom::Full {
+            coefficient: 1.,
+            exponent: 0,
+            next: Box::new(Polynom::Full {
+                coefficient: 2.,
+                exponent: 1,
+                next: Box::new(Polynom::Full {
+                    coefficient: 3.,
+                    exponent: 2,
+                    next: Box::new(Polynom::Full {
+                        coefficient: -1.,
+                        exponent: 1,
+                        next: Box::new(Polynom::Empty),
+                    }),
+                }),
+            }),
+        };
+
+        // when:
+        let actual = under_test.taylor_at(0.).unwrap();
+
+        // then:
+        assert_eq!(actual.to_string(), "3x^2 + 1x + 1");
+    }
+}